@@ -1,10 +1,56 @@
 //! Module that defines the public compilation API of [`Engine`].
 
+use crate::api::options::LangOptions;
 use crate::parser::{ParseResult, ParseState};
 use crate::{Engine, Scope, AST};
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 
+/// Options for compiling a script via [`Engine::compile_with_options`], overriding parts of the
+/// [`Engine`] configuration.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[non_exhaustive]
+pub struct CompileOptions {
+    /// Optimization level to use instead of the [`Engine`]'s (if any). Default [`None`].
+    ///
+    /// Not available under `no_optimize`.
+    #[cfg(not(feature = "no_optimize"))]
+    pub optimization_level: Option<crate::OptimizationLevel>,
+    /// Always compile in _Strict Variables_ mode, even if it is not turned on for the [`Engine`]?
+    /// Default `false`.
+    pub strict_variables: bool,
+}
+
+impl CompileOptions {
+    /// Create a default [`CompileOptions`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            #[cfg(not(feature = "no_optimize"))]
+            optimization_level: None,
+            strict_variables: false,
+        }
+    }
+    /// Set the optimization level to use instead of the [`Engine`]'s.
+    ///
+    /// Not available under `no_optimize`.
+    #[cfg(not(feature = "no_optimize"))]
+    #[inline(always)]
+    #[must_use]
+    pub const fn with_optimization_level(mut self, level: crate::OptimizationLevel) -> Self {
+        self.optimization_level = Some(level);
+        self
+    }
+    /// Set whether to always compile in _Strict Variables_ mode.
+    #[inline(always)]
+    #[must_use]
+    pub const fn with_strict_variables(mut self, enable: bool) -> Self {
+        self.strict_variables = enable;
+        self
+    }
+}
+
 impl Engine {
     /// Compile a string into an [`AST`], which can be used later for evaluation.
     ///
@@ -70,6 +116,55 @@ impl Engine {
     pub fn compile_with_scope(&self, scope: &Scope, script: impl AsRef<str>) -> ParseResult<AST> {
         self.compile_scripts_with_scope(scope, &[script])
     }
+    /// Compile a string into an [`AST`] using own scope and [`CompileOptions`] overriding parts of
+    /// the [`Engine`] configuration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{CompileOptions, Engine, Scope};
+    ///
+    /// let engine = Engine::new();
+    /// let options = CompileOptions::new().with_strict_variables(true);
+    ///
+    /// assert!(engine.compile_with_options(&Scope::new(), "x + 1", options).is_err());
+    /// assert!(engine.compile_with_options(&Scope::new(), "let x = 1; x + 1", options).is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn compile_with_options(
+        &self,
+        scope: &Scope,
+        script: impl AsRef<str>,
+        options: CompileOptions,
+    ) -> ParseResult<AST> {
+        let scripts = [script];
+        let (stream, tc) = self.lex(&scripts);
+
+        let mut lang_options = self.options;
+        if options.strict_variables {
+            lang_options |= LangOptions::STRICT_VAR;
+        }
+
+        let input = &mut stream.peekable();
+        let lib = &mut <_>::default();
+        let state = ParseState::new(Some(scope), input, tc.clone(), lib);
+        let mut _ast = self.parse_with_options(
+            state,
+            lang_options,
+            #[cfg(not(feature = "no_optimize"))]
+            options
+                .optimization_level
+                .unwrap_or(self.optimization_level),
+        )?;
+        #[cfg(feature = "metadata")]
+        {
+            let global_comments = &tc.borrow().global_comments;
+            _ast.doc = global_comments.into();
+        }
+        Ok(_ast)
+    }
     /// Compile a string into an [`AST`] using own scope, which can be used later for evaluation,
     /// embedding all imported modules.
     ///
@@ -246,6 +246,79 @@ impl Engine {
         self.progress = Some(Box::new(callback));
         self
     }
+    /// Register a callback that will be invoked whenever a script executes an `import` statement.
+    ///
+    /// Not available under `no_module`.
+    ///
+    /// # Callback Function Signature
+    ///
+    /// `Fn(path: &str, name: &str) -> Result<(), Box<EvalAltResult>>`
+    ///
+    /// where:
+    /// * `path`: the path of the module to import, as evaluated from the `import` statement.
+    /// * `name`: the name the module is imported as (i.e. the `as` alias), or empty if none.
+    ///
+    /// ## Ordering
+    ///
+    /// The callback is invoked after the path is evaluated, but before the module is resolved by
+    /// any [module resolver][crate::ModuleResolver].  It is therefore invoked for every `import`
+    /// executed, even when the module resolver returns a cached module, and even when the module
+    /// cannot be found.
+    ///
+    /// Circular imports are detected by the module resolver (e.g.
+    /// [`FileModuleResolver`][crate::module_resolvers::FileModuleResolver]), so the callback is
+    /// invoked before any circular import error is raised.
+    ///
+    /// ## Raising errors
+    ///
+    /// Return `Err(...)` to veto the import.  The error is raised at the position of the `import`
+    /// path, and the module is not resolved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// # use std::sync::{Arc, RwLock};
+    /// use rhai::{Engine, EvalAltResult, Module, Position};
+    /// use rhai::module_resolvers::StaticModuleResolver;
+    ///
+    /// let mut resolver = StaticModuleResolver::new();
+    /// resolver.insert("hello", Module::new());
+    /// resolver.insert("secret", Module::new());
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.set_module_resolver(resolver);
+    ///
+    /// let log = Arc::new(RwLock::new(Vec::new()));
+    /// let logger = log.clone();
+    ///
+    /// engine.on_import(move |path, name| {
+    ///     logger.write().unwrap().push(format!("{path} as {name}"));
+    ///
+    ///     if path == "secret" {
+    ///         Err(EvalAltResult::ErrorModuleNotFound(path.into(), Position::NONE).into())
+    ///     } else {
+    ///         Ok(())
+    ///     }
+    /// });
+    ///
+    /// engine.run(r#"import "hello" as h;"#)?;
+    ///
+    /// assert!(engine.run(r#"import "secret" as s;"#).is_err());
+    ///
+    /// assert_eq!(*log.read().unwrap(), ["hello as h", "secret as s"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "no_module"))]
+    #[inline(always)]
+    pub fn on_import(
+        &mut self,
+        callback: impl Fn(&str, &str) -> RhaiResultOf<()> + SendSync + 'static,
+    ) -> &mut Self {
+        self.import_filter = Some(Box::new(callback));
+        self
+    }
     /// Override default action of `print` (print to stdout using [`println!`])
     ///
     /// # Example
@@ -3,7 +3,7 @@
 #![cfg(any(not(target_family = "wasm"), not(target_os = "unknown")))]
 
 use crate::types::dynamic::Variant;
-use crate::{Engine, RhaiResultOf, Scope, AST, ERR};
+use crate::{CompileOptions, Engine, RhaiResultOf, Scope, AST, ERR};
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 use std::{
@@ -113,6 +113,23 @@ impl Engine {
             Ok(ast)
         })
     }
+    /// Compile a script file into an [`AST`] using own scope and [`CompileOptions`] overriding
+    /// parts of the [`Engine`] configuration.
+    ///
+    /// Not available under `no_std` or `WASM`.
+    #[inline]
+    pub fn compile_file_with_options(
+        &self,
+        scope: &Scope,
+        path: PathBuf,
+        options: CompileOptions,
+    ) -> RhaiResultOf<AST> {
+        Self::read_file(&path).and_then(|contents| {
+            let mut ast = self.compile_with_options(scope, contents, options)?;
+            ast.set_source(path.to_string_lossy().as_ref());
+            Ok(ast)
+        })
+    }
     /// Evaluate a script file, returning the result value or an error.
     ///
     /// Not available under `no_std` or `WASM`.
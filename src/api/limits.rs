@@ -60,6 +60,11 @@ pub struct Limits {
     pub function_expr_depth: Option<NonZeroUsize>,
     /// Maximum number of operations allowed to run.
     pub num_operations: Option<NonZeroU64>,
+    /// Maximum number of calls to script-defined functions allowed to run.
+    ///
+    /// Not available under `no_function`.
+    #[cfg(not(feature = "no_function"))]
+    pub num_script_fn_calls: Option<NonZeroUsize>,
     /// Maximum number of variables allowed at any instant.
     ///
     /// Set to zero to effectively disable creating variables.
@@ -105,6 +110,8 @@ impl Limits {
             #[cfg(not(feature = "no_function"))]
             function_expr_depth: NonZeroUsize::new(default_limits::MAX_FUNCTION_EXPR_DEPTH),
             num_operations: None,
+            #[cfg(not(feature = "no_function"))]
+            num_script_fn_calls: None,
             num_variables: usize::MAX,
             #[cfg(not(feature = "no_function"))]
             num_functions: usize::MAX,
@@ -188,6 +195,38 @@ impl Engine {
             None => 0,
         }
     }
+    /// Set the maximum number of calls to script-defined functions (including those defined in
+    /// [modules][crate::Module]) allowed for a script to run (0 for unlimited).
+    ///
+    /// This bounds run-away recursion (or looping over recursive functions) independently of the
+    /// call-stack depth limit set via [`set_max_call_levels`][Engine::set_max_call_levels].
+    ///
+    /// Each function call also counts as one operation, so this limit is checked in addition to
+    /// the limit set via [`set_max_operations`][Engine::set_max_operations] -- whichever is
+    /// exceeded first terminates the script.  Both counts start afresh for each evaluation run.
+    ///
+    /// Not available under `unchecked` or `no_function`.
+    #[cfg(not(feature = "no_function"))]
+    #[inline(always)]
+    pub fn set_max_script_fn_calls(&mut self, calls: usize) -> &mut Self {
+        self.limits.num_script_fn_calls = NonZeroUsize::new(calls);
+        self
+    }
+    /// The maximum number of calls to script-defined functions allowed for a script to run
+    /// (0 for unlimited).
+    ///
+    /// Not available under `unchecked` or `no_function`.
+    #[inline]
+    #[must_use]
+    pub const fn max_script_fn_calls(&self) -> usize {
+        #[cfg(not(feature = "no_function"))]
+        return match self.limits.num_script_fn_calls {
+            Some(n) => n.get(),
+            None => 0,
+        };
+        #[cfg(feature = "no_function")]
+        return 0;
+    }
     /// Set the maximum number of variables allowed for a script at any instant.
     ///
     /// Not available under `unchecked`.
@@ -54,6 +54,12 @@ pub mod default_limits {
 
 impl Engine {
     /// Set the maximum number of strings to be interned.
+    ///
+    /// The strings interner is owned by the [`Engine`] and shared by all compilations it performs,
+    /// including those of module scripts loaded by [module resolvers][crate::ModuleResolver], so
+    /// identifiers and string literals common to many scripts are allocated only once.
+    ///
+    /// Set to zero to disable interning.
     #[inline(always)]
     pub fn set_max_strings_interned(&mut self, max: usize) -> &mut Self {
         if max == 0 {
@@ -102,6 +108,150 @@ impl Engine {
         self
     }
 
+    /// Resolve a [module][crate::Module] and import only the variables and functions whose names
+    /// match a glob pattern.
+    ///
+    /// In the pattern, `*` matches any sequence of characters and `?` matches any single character.
+    ///
+    /// Matching variables are pushed into the [`Scope`][crate::Scope] as constants.
+    /// Matching functions are returned in a new [module][crate::Module], which can be registered
+    /// via [`register_global_module`][Engine::register_global_module] to call them without
+    /// qualification.
+    ///
+    /// An error is returned if nothing in the [module][crate::Module] matches the pattern.
+    ///
+    /// Not available under `no_module`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Module, Scope};
+    /// use rhai::module_resolvers::StaticModuleResolver;
+    ///
+    /// let mut module = Module::new();
+    /// module.set_var("math_pi", 3_i64);
+    /// module.set_native_fn("math_double", |x: i64| Ok(x * 2));
+    /// module.set_native_fn("text_len", |s: &str| Ok(s.len() as i64));
+    ///
+    /// let mut resolver = StaticModuleResolver::new();
+    /// resolver.insert("utils", module);
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.set_module_resolver(resolver);
+    ///
+    /// let mut scope = Scope::new();
+    /// let functions = engine.import_matching(&mut scope, "utils", "math_*")?;
+    /// engine.register_global_module(functions.into());
+    ///
+    /// assert_eq!(engine.eval_with_scope::<i64>(&mut scope, "math_double(math_pi)")?, 6);
+    /// assert!(engine.eval::<i64>(r#"text_len("hello")"#).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "no_module"))]
+    pub fn import_matching(
+        &self,
+        scope: &mut crate::Scope,
+        path: &str,
+        pattern: &str,
+    ) -> crate::RhaiResultOf<crate::Module> {
+        let pos = crate::Position::NONE;
+
+        let module = self.module_resolver().resolve(self, None, path, pos)?;
+        let mut module = crate::func::shared_take_or_clone(module);
+
+        module.retain_by_name(|name| glob_match(pattern, name));
+
+        if module.iter_var().next().is_none() && module.iter_fn().next().is_none() {
+            let err = format!("Nothing matches '{pattern}'");
+            let err = crate::ERR::ErrorRuntime(err.into(), pos);
+            return Err(crate::ERR::ErrorInModule(path.into(), err.into(), pos).into());
+        }
+
+        for (name, value) in module.iter_var() {
+            scope.push_constant_dynamic(name, value.clone());
+        }
+
+        Ok(module)
+    }
+
+    /// Add a deferred import of a [module][crate::Module] into a [`Scope`][crate::Scope] under an
+    /// alias, as if by `import "path" as alias;`, except that the module is only resolved (using
+    /// the [module resolver][Engine::module_resolver]) when a qualified access through the alias is
+    /// first evaluated.
+    ///
+    /// This avoids resolving modules on code paths that never use them.
+    /// A module that fails to resolve only raises an error when it is accessed.
+    ///
+    /// Modules imported by `import` statements and [static modules][Engine::register_static_module]
+    /// with the same name take precedence.
+    ///
+    /// The alias is not known to the parser, so it is rejected under
+    /// [strict variables mode][Engine::set_strict_variables].
+    ///
+    /// Not available under `no_module`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Module, Scope};
+    /// use rhai::module_resolvers::StaticModuleResolver;
+    ///
+    /// let mut module = Module::new();
+    /// module.set_var("answer", 42_i64);
+    ///
+    /// let mut resolver = StaticModuleResolver::new();
+    /// resolver.insert("question", module);
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.set_module_resolver(resolver);
+    ///
+    /// let mut scope = Scope::new();
+    /// engine.defer_import(&mut scope, "question", "q");
+    /// engine.defer_import(&mut scope, "missing", "m");
+    ///
+    /// // "missing" is never resolved
+    /// assert_eq!(engine.eval_with_scope::<i64>(&mut scope, "if false { m::x } else { q::answer }")?, 42);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "no_module"))]
+    #[inline]
+    pub fn defer_import(
+        &self,
+        scope: &mut crate::Scope,
+        path: impl Into<crate::ImmutableString>,
+        alias: impl Into<crate::Identifier>,
+    ) {
+        let handle = crate::module::DeferredImport {
+            path: path.into(),
+            module: Default::default(),
+        };
+        scope.push_constant(alias, handle);
+    }
+
+    /// Set a [module][crate::Module] whose variables and functions are made available to all
+    /// module scripts loaded by a [`FileModuleResolver`][crate::module_resolvers::FileModuleResolver]
+    /// while they are being evaluated.
+    ///
+    /// Variables in the prelude are visible as constants.  Functions in the prelude must be in the
+    /// global namespace ([`FnNamespace::Global`][crate::FnNamespace::Global]) in order to be called
+    /// without qualification.
+    ///
+    /// Prelude entries do not become part of the resolved [module][crate::Module] unless they are
+    /// re-exported by the module script (e.g. `export PI;`).
+    ///
+    /// Not available under `no_module`.
+    #[cfg(not(feature = "no_module"))]
+    #[inline(always)]
+    pub fn set_module_prelude(&mut self, mut module: crate::Module) -> &mut Self {
+        module.build_index();
+        self.module_prelude = Some(module.into());
+        self
+    }
+
     /// Disable a particular keyword or operator in the language.
     ///
     /// # Examples
@@ -259,3 +409,39 @@ impl Engine {
         self
     }
 }
+
+/// Match a name against a glob pattern, where `*` matches any sequence of characters and `?`
+/// matches any single character.
+#[cfg(not(feature = "no_module"))]
+#[must_use]
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                // Let the last `*` match one more character
+                Some((bp, bt)) => {
+                    backtrack = Some((bp, bt + 1));
+                    p = bp + 1;
+                    t = bt + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
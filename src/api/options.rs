@@ -32,6 +32,9 @@ bitflags! {
         const FAIL_ON_INVALID_MAP_PROPERTY = 0b_0001_0000_0000;
         /// Fast operators mode?
         const FAST_OPS = 0b_0010_0000_0000;
+        /// Bind modules imported without an alias under the names of their paths?
+        #[cfg(not(feature = "no_module"))]
+        const IMPLICIT_IMPORT_ALIAS = 0b_0100_0000_0000;
     }
 }
 
@@ -207,4 +210,112 @@ impl Engine {
         self.options.set(LangOptions::FAST_OPS, enable);
         self
     }
+    /// Are modules imported without an `as` alias bound under the names of their paths?
+    /// Default is `false`.
+    ///
+    /// Not available under `no_module`.
+    #[cfg(not(feature = "no_module"))]
+    #[inline(always)]
+    #[must_use]
+    pub const fn implicit_import_alias(&self) -> bool {
+        self.options.intersects(LangOptions::IMPLICIT_IMPORT_ALIAS)
+    }
+    /// Set whether modules imported without an `as` alias are bound under the names of their
+    /// paths.
+    ///
+    /// When enabled, `import "lib/util";` binds the module as if it were
+    /// `import "lib/util" as util;`.  The name is the final component of the path (separated by
+    /// `/` or `\`), without any extension (e.g. `"util.rhai"` is bound as `util`).
+    ///
+    /// The path must be a string literal and the name must be a valid identifier; otherwise the
+    /// module is imported without a name, as usual.
+    ///
+    /// Not available under `no_module`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Module};
+    /// use rhai::module_resolvers::StaticModuleResolver;
+    ///
+    /// let mut module = Module::new();
+    /// module.set_var("answer", 42_i64);
+    ///
+    /// let mut resolver = StaticModuleResolver::new();
+    /// resolver.insert("lib/util", module);
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.set_module_resolver(resolver);
+    /// engine.set_implicit_import_alias(true);
+    ///
+    /// assert_eq!(engine.eval::<i64>(r#"import "lib/util"; util::answer"#)?, 42);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "no_module"))]
+    #[inline(always)]
+    pub fn set_implicit_import_alias(&mut self, enable: bool) -> &mut Self {
+        self.options.set(LangOptions::IMPLICIT_IMPORT_ALIAS, enable);
+        self
+    }
+}
+
+impl Engine {
+    /// Are floating-point numbers supported?
+    ///
+    /// This is `false` under `no_float`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn supports_float() -> bool {
+        cfg!(not(feature = "no_float"))
+    }
+    /// Are `Decimal` numbers supported?
+    ///
+    /// This is `true` only under `decimal`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn supports_decimal() -> bool {
+        cfg!(feature = "decimal")
+    }
+    /// Are arrays and BLOB's supported?
+    ///
+    /// This is `false` under `no_index`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn supports_index() -> bool {
+        cfg!(not(feature = "no_index"))
+    }
+    /// Are object maps and properties supported?
+    ///
+    /// This is `false` under `no_object`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn supports_object() -> bool {
+        cfg!(not(feature = "no_object"))
+    }
+    /// Are script-defined functions supported?
+    ///
+    /// This is `false` under `no_function`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn supports_function() -> bool {
+        cfg!(not(feature = "no_function"))
+    }
+    /// Are closures capturing shared variables supported?
+    ///
+    /// This is `false` under `no_closure` or `no_function`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn supports_closure() -> bool {
+        cfg!(not(any(feature = "no_closure", feature = "no_function")))
+    }
+    /// Are modules and `import` statements supported?
+    ///
+    /// This is `false` under `no_module`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn supports_module() -> bool {
+        cfg!(not(feature = "no_module"))
+    }
 }
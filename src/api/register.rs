@@ -631,11 +631,105 @@ impl Engine {
         // Make sure the global namespace is created.
         let _ = self.global_namespace_mut();
 
+        #[cfg(not(feature = "no_custom_syntax"))]
+        self.register_module_operators(&module);
+
         // Insert the module into the front.
         // The first module is always the global namespace.
         self.global_modules.insert(1, module);
         self
     }
+    /// Register only the functions with particular names from a [`Module`] into the global
+    /// namespace of [`Engine`].
+    ///
+    /// Matching functions (all overloads of each name) are copied into a new [`Module`], which is
+    /// then registered via [`register_global_module`][Engine::register_global_module].  This is
+    /// useful to expose a curated subset of a larger module without namespace qualifications.
+    ///
+    /// Returns the names that do not match any function in the [`Module`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Module};
+    ///
+    /// let mut module = Module::new();
+    /// module.set_native_fn("inc", |x: i64| Ok(x + 1));
+    /// module.set_native_fn("dec", |x: i64| Ok(x - 1));
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// let missing = engine.register_module_functions(&module, &["inc", "foo"]);
+    /// assert_eq!(missing, ["foo"]);
+    ///
+    /// assert_eq!(engine.eval::<i64>("inc(41)")?, 42);
+    /// assert!(engine.eval::<i64>("dec(43)").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn register_module_functions(&mut self, module: &Module, names: &[&str]) -> Vec<String> {
+        let functions = module.clone_fns_filtered(|name| names.contains(&name));
+
+        let missing = names
+            .iter()
+            .filter(|&&name| {
+                functions.get_var_args_fn(name).is_none()
+                    && !functions.iter_fn_info().any(|(_, f, _)| f == name)
+            })
+            .map(|&name| name.to_string())
+            .collect();
+
+        if !functions.is_empty() {
+            self.register_global_module(functions.into());
+        }
+
+        missing
+    }
+    /// Remove a shared [`Module`] previously registered via
+    /// [`register_global_module`][Engine::register_global_module] from the global namespace of
+    /// [`Engine`].
+    ///
+    /// The [`Module`] is identified by the shared pointer itself, not by its contents.
+    /// Returns `true` if the [`Module`] was found and removed.
+    ///
+    /// Only the functions and type iterators contributed by this [`Module`] are removed.
+    /// In particular, if a module loaded later overrides one of its functions, the overriding
+    /// function stays.  Likewise, if this [`Module`] overrode a function of a module loaded earlier,
+    /// the earlier function becomes visible again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::{Engine, Module, Shared};
+    ///
+    /// let mut module = Module::new();
+    /// module.set_native_fn("calc", |x: i64| Ok(x + 1));
+    /// let module: Shared<Module> = module.into();
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.register_global_module(module.clone());
+    /// assert_eq!(engine.eval::<i64>("calc(41)").unwrap(), 42);
+    ///
+    /// assert!(engine.unregister_global_module(&module));
+    /// assert!(engine.eval::<i64>("calc(41)").is_err());
+    /// ```
+    #[inline]
+    pub fn unregister_global_module(&mut self, module: &SharedModule) -> bool {
+        // The first module is always the global namespace and is never removed.
+        match self
+            .global_modules
+            .iter()
+            .skip(1)
+            .position(|m| Shared::ptr_eq(m, module))
+        {
+            Some(index) => {
+                self.global_modules.remove(index + 1);
+                true
+            }
+            None => false,
+        }
+    }
     /// Register a shared [`Module`] as a static module namespace with the [`Engine`].
     ///
     /// Functions marked [`FnNamespace::Global`][`crate::FnNamespace::Global`] and type iterators are exposed to scripts without
@@ -712,9 +806,25 @@ impl Engine {
             }
         }
 
+        #[cfg(not(feature = "no_custom_syntax"))]
+        self.register_module_operators(&module);
+
         register_static_module_raw(&mut self.global_sub_modules, name.as_ref(), module);
         self
     }
+    /// Register the custom operators defined by a [`Module`] and its sub-modules.
+    ///
+    /// Registration errors are deliberately ignored, because registering a module cannot fail:
+    /// an operator that is a reserved keyword or symbol is simply not available to scripts, while
+    /// its function is still registered.  An operator that is already registered (by
+    /// [`register_custom_operator`][Engine::register_custom_operator] or another module) takes
+    /// the precedence of the latest registration.
+    #[cfg(not(feature = "no_custom_syntax"))]
+    fn register_module_operators(&mut self, module: &Module) {
+        for (op, precedence) in module.iter_custom_operators() {
+            let _ = self.register_custom_operator(op, precedence.get());
+        }
+    }
     /// _(metadata)_ Generate a list of all registered functions.
     /// Exported under the `metadata` feature only.
     ///
@@ -755,6 +755,66 @@ impl AST {
             _ => None,
         })
     }
+    /// Get all the `import` statements in the [`AST`], including those inside function bodies
+    /// (if any).
+    ///
+    /// Not available under `no_module`.
+    ///
+    /// Each import is returned as a tuple of (path, alias, position).
+    ///
+    /// The path is [`None`] if it is not a literal string (i.e. it is computed at runtime).
+    /// The alias is [`None`] if the `import` statement has no `as` clause.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::Engine;
+    ///
+    /// let engine = Engine::new();
+    ///
+    /// let ast = engine.compile(
+    /// r#"
+    ///     import "hello" as h;
+    ///     import "world";
+    ///     let name = "foo";
+    ///     import name as x;
+    /// "#)?;
+    ///
+    /// let imports: Vec<_> = ast
+    ///     .imports()
+    ///     .into_iter()
+    ///     .map(|(path, alias, _)| (path, alias))
+    ///     .collect();
+    ///
+    /// assert_eq!(imports, [
+    ///     (Some("hello".to_string()), Some("h".to_string())),
+    ///     (Some("world".to_string()), None),
+    ///     (None, Some("x".to_string())),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "no_module"))]
+    #[must_use]
+    pub fn imports(&self) -> Vec<(Option<String>, Option<String>, Position)> {
+        let mut imports = Vec::new();
+
+        self._walk(&mut |path: &[ASTNode]| {
+            if let Some(ASTNode::Stmt(Stmt::Import(x, pos))) = path.last() {
+                let (expr, alias) = &**x;
+                let path = match expr {
+                    Expr::StringConstant(s, ..) => Some(s.to_string()),
+                    _ => None,
+                };
+                let alias = (!alias.name.is_empty()).then(|| alias.name.to_string());
+                imports.push((path, alias, *pos));
+            }
+            true
+        });
+
+        imports
+    }
     /// _(internals)_ Recursively walk the [`AST`], including function bodies (if any).
     /// Return `false` from the callback to terminate the walk.
     /// Exported under the `internals` feature only.
@@ -23,6 +23,8 @@ use crate::OnceCell;
 
 static HASHING_SEED: OnceCell<Option<[u64; 4]>> = OnceCell::new();
 
+pub use crate::func::calc_fn_hash_with_seed;
+
 #[allow(deprecated)]
 pub use crate::api::deprecated::config::hashing::{get_ahash_seed, set_ahash_seed};
 
@@ -33,6 +35,14 @@ pub use crate::api::deprecated::config::hashing::{get_ahash_seed, set_ahash_seed
 ///
 /// Set the hashing seed to all zeros effectively disables stable hashing.
 ///
+/// With a fixed non-zero seed, function hashes are deterministic across runs and processes (on
+/// the same platform and for the same version of Rhai).  Use
+/// [`calc_fn_hash_with_seed`] to calculate them ahead of time.
+///
+/// This does not hold for the hashes of native Rust functions, which also depend on the
+/// [`TypeId`][std::any::TypeId]'s of their parameter types.  These are not guaranteed to be the
+/// same across different builds of the same program.
+///
 /// # Warning
 ///
 /// * You can only call this function **ONCE** for the entire duration of program execution.
@@ -55,6 +55,12 @@ pub const OP_EQUALS: &str = Token::EqualsTo.literal_syntax();
 /// The `in` operator is implemented as a call to this function.
 pub const OP_CONTAINS: &str = "contains";
 
+/// Standard type conversion function.
+///
+/// Conversions registered via [`Module::set_convert_fn`][crate::Module::set_convert_fn] are
+/// called through this function, e.g. `convert(value, "TypeName")`.
+pub const FN_CONVERT: &str = "convert";
+
 /// Standard not operator.
 pub const OP_NOT: &str = Token::Bang.literal_syntax();
 
@@ -95,6 +101,12 @@ pub struct Engine {
     /// A module resolution service.
     #[cfg(not(feature = "no_module"))]
     pub(crate) module_resolver: Option<Box<dyn crate::ModuleResolver>>,
+    /// A [module][crate::Module] made available to all module scripts during their evaluation.
+    #[cfg(not(feature = "no_module"))]
+    pub(crate) module_prelude: Option<crate::SharedModule>,
+    /// Callback closure invoked before each module `import` is resolved.
+    #[cfg(not(feature = "no_module"))]
+    pub(crate) import_filter: Option<Box<crate::func::native::OnImportCallback>>,
 
     /// Strings interner.
     pub(crate) interned_strings: Option<Locked<StringsInterner>>,
@@ -164,7 +176,9 @@ impl fmt::Debug for Engine {
         f.field("global_modules", &self.global_modules);
 
         #[cfg(not(feature = "no_module"))]
-        f.field("global_sub_modules", &self.global_sub_modules);
+        f.field("global_sub_modules", &self.global_sub_modules)
+            .field("module_prelude", &self.module_prelude)
+            .field("import_filter", &self.import_filter.is_some());
 
         f.field("disabled_symbols", &self.disabled_symbols);
 
@@ -241,6 +255,10 @@ impl Engine {
 
         #[cfg(not(feature = "no_module"))]
         module_resolver: None,
+        #[cfg(not(feature = "no_module"))]
+        module_prelude: None,
+        #[cfg(not(feature = "no_module"))]
+        import_filter: None,
 
         interned_strings: None,
         disabled_symbols: BTreeSet::new(),
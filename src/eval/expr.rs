@@ -40,6 +40,57 @@ impl Engine {
             |offset| global.get_shared_import(offset),
         )
     }
+    /// Search for a deferred import matching the root of a namespace within the scope,
+    /// resolving the module on first use.
+    #[cfg(not(feature = "no_module"))]
+    pub(crate) fn search_deferred_imports(
+        &self,
+        global: &mut GlobalRuntimeState,
+        scope: &Scope,
+        namespace: &crate::ast::Namespace,
+    ) -> RhaiResultOf<Option<crate::SharedModule>> {
+        use crate::func::{locked_read, locked_write};
+        use crate::ModuleResolver;
+
+        let handle = match scope
+            .get(namespace.root())
+            .and_then(|v| v.downcast_ref::<crate::module::DeferredImport>())
+        {
+            Some(handle) => handle,
+            None => return Ok(None),
+        };
+
+        if let Some(module) = locked_read(&handle.module).unwrap().as_ref() {
+            return Ok(Some(module.clone()));
+        }
+
+        // Guard against too many modules
+        #[cfg(not(feature = "unchecked"))]
+        if global.num_modules_loaded >= self.max_modules() {
+            return Err(ERR::ErrorTooManyModules(namespace.position()).into());
+        }
+
+        let module = self.module_resolver().resolve(
+            self,
+            global.source(),
+            &handle.path,
+            namespace.position(),
+        )?;
+
+        let module = if module.is_indexed() {
+            module
+        } else {
+            // Index the module (making a clone copy if necessary) if it is not indexed
+            let mut m = crate::func::shared_take_or_clone(module);
+            m.build_index();
+            m.into()
+        };
+
+        global.num_modules_loaded += 1;
+        *locked_write(&handle.module).unwrap() = Some(module.clone());
+
+        Ok(Some(module))
+    }
     /// Search for a variable within the scope
     ///
     /// # Panics
@@ -163,23 +214,30 @@ impl Engine {
                 #[cfg(not(feature = "no_module"))]
                 (_, var_name, ns, hash_var) if !ns.is_empty() => {
                     // foo:bar::baz::VARIABLE
-                    if let Some(module) = self.search_imports(global, ns) {
-                        return module.get_qualified_var(*hash_var).map_or_else(
-                            || {
-                                let sep = crate::engine::NAMESPACE_SEPARATOR;
-
-                                Err(ERR::ErrorVariableNotFound(
-                                    format!("{ns}{sep}{var_name}"),
-                                    ns.position(),
-                                )
-                                .into())
-                            },
-                            |mut target| {
-                                // Module variables are constant
-                                target.set_access_mode(AccessMode::ReadOnly);
-                                Ok(target.into())
-                            },
-                        );
+                    let module = match self.search_imports(global, ns) {
+                        Some(module) => Some(module),
+                        None => self.search_deferred_imports(global, scope, ns)?,
+                    };
+
+                    if let Some(module) = module {
+                        return module
+                            .get_qualified_var(*hash_var, &ns.path[1..], var_name)
+                            .map_or_else(
+                                || {
+                                    let sep = crate::engine::NAMESPACE_SEPARATOR;
+
+                                    Err(ERR::ErrorVariableNotFound(
+                                        format!("{ns}{sep}{var_name}"),
+                                        ns.position(),
+                                    )
+                                    .into())
+                                },
+                                |mut target| {
+                                    // Module variables are constant
+                                    target.set_access_mode(AccessMode::ReadOnly);
+                                    Ok(target.into())
+                                },
+                            );
                     }
 
                     // global::VARIABLE
@@ -39,9 +39,18 @@ pub struct GlobalRuntimeState {
     pub source: Option<ImmutableString>,
     /// Number of operations performed.
     pub num_operations: u64,
+    /// Number of calls to script-defined functions made.
+    #[cfg(not(feature = "no_function"))]
+    pub num_script_fn_calls: usize,
     /// Number of modules loaded.
     #[cfg(not(feature = "no_module"))]
     pub num_modules_loaded: usize,
+    /// Resolved identities (e.g. canonical file paths) of the [modules][crate::Module] being
+    /// evaluated by [module resolvers][crate::ModuleResolver], outermost first.
+    ///
+    /// Used to detect circular imports.
+    #[cfg(not(feature = "no_module"))]
+    pub(crate) importing: crate::StaticVec<ImmutableString>,
     /// The current nesting level of function calls.
     pub level: usize,
     /// Level of the current scope.
@@ -70,6 +79,12 @@ pub struct GlobalRuntimeState {
     pub constants: Option<SharedGlobalConstants>,
     /// Custom state that can be used by the external host.
     pub tag: Dynamic,
+    /// Callback closure overriding the [`Engine`]'s `print` callback, if any.
+    #[cfg(not(feature = "no_module"))]
+    pub(crate) print: Option<crate::Shared<crate::func::native::OnPrintCallback>>,
+    /// Callback closure overriding the [`Engine`]'s `debug` callback, if any.
+    #[cfg(not(feature = "no_module"))]
+    pub(crate) debug: Option<crate::Shared<crate::func::native::OnDebugCallback>>,
     /// Debugging interface.
     #[cfg(feature = "debugging")]
     pub(crate) debugger: Option<Box<super::Debugger>>,
@@ -91,8 +106,12 @@ impl Engine {
             lib: crate::ThinVec::new(),
             source: None,
             num_operations: 0,
+            #[cfg(not(feature = "no_function"))]
+            num_script_fn_calls: 0,
             #[cfg(not(feature = "no_module"))]
             num_modules_loaded: 0,
+            #[cfg(not(feature = "no_module"))]
+            importing: crate::StaticVec::new_const(),
             scope_level: 0,
             level: 0,
             always_search_scope: false,
@@ -104,6 +123,11 @@ impl Engine {
 
             tag: self.default_tag().clone(),
 
+            #[cfg(not(feature = "no_module"))]
+            print: None,
+            #[cfg(not(feature = "no_module"))]
+            debug: None,
+
             #[cfg(feature = "debugging")]
             debugger: self.debugger_interface.as_ref().map(|x| {
                 let dbg = crate::eval::Debugger::new(crate::eval::DebuggerStatus::Init);
@@ -323,14 +347,19 @@ impl fmt::Debug for GlobalRuntimeState {
         #[cfg(not(feature = "no_module"))]
         f.field("imports", &self.scan_imports_raw().collect::<Vec<_>>())
             .field("num_modules_loaded", &self.num_modules_loaded)
+            .field("importing", &self.importing)
             .field("embedded_module_resolver", &self.embedded_module_resolver);
 
         #[cfg(not(feature = "no_function"))]
         f.field("lib", &self.lib);
 
         f.field("source", &self.source)
-            .field("num_operations", &self.num_operations)
-            .field("level", &self.level)
+            .field("num_operations", &self.num_operations);
+
+        #[cfg(not(feature = "no_function"))]
+        f.field("num_script_fn_calls", &self.num_script_fn_calls);
+
+        f.field("level", &self.level)
             .field("scope_level", &self.scope_level)
             .field("always_search_scope", &self.always_search_scope);
 
@@ -340,6 +369,11 @@ impl fmt::Debug for GlobalRuntimeState {
 
         f.field("tag", &self.tag);
 
+        #[cfg(not(feature = "no_module"))]
+        f.field("print", &self.print.is_some());
+        #[cfg(not(feature = "no_module"))]
+        f.field("debug", &self.debug.is_some());
+
         #[cfg(feature = "debugging")]
         f.field("debugger", &self.debugger);
 
@@ -912,13 +912,25 @@ impl Engine {
 
                 let path_pos = expr.start_position();
 
+                // Notify the import filter, which may veto the import
+                if let Some(ref filter) = self.import_filter {
+                    filter(&path, &export.name).map_err(|err| err.fill_position(path_pos))?;
+                }
+
                 let resolver = global.embedded_module_resolver.clone();
 
                 let module = resolver
                     .as_ref()
                     .and_then(
                         |r| match r.resolve_raw(self, global, scope, &path, path_pos) {
-                            Err(err) if matches!(*err, ERR::ErrorModuleNotFound(..)) => None,
+                            Err(err)
+                                if matches!(
+                                    *err,
+                                    ERR::ErrorModuleNotFound(..) | ERR::ErrorModuleFileNotFound(..)
+                                ) =>
+                            {
+                                None
+                            }
                             result => Some(result),
                         },
                     )
@@ -355,9 +355,24 @@ impl Engine {
 
         // Check if function access already in the cache
         let local_entry = &mut None;
+        let var_args_entry;
         let a = Some(&mut *args);
         let func = self.resolve_fn(global, caches, local_entry, op_token, hash, a, true);
 
+        // Then check variadic functions in the global namespace
+        let func = match func {
+            Some(entry) => Some(entry),
+            None => {
+                var_args_entry = self.global_modules.iter().find_map(|m| {
+                    m.get_var_args_fn(name).map(|f| FnResolutionCacheEntry {
+                        func: f.clone(),
+                        source: m.id_raw().cloned(),
+                    })
+                });
+                var_args_entry.as_ref()
+            }
+        };
+
         if let Some(FnResolutionCacheEntry { func, source }) = func {
             debug_assert!(func.is_native());
 
@@ -457,7 +472,11 @@ impl Engine {
             // See if the function match print/debug (which requires special processing)
             return Ok(match name {
                 KEYWORD_PRINT => {
-                    if let Some(ref print) = self.print {
+                    let print = self.print.as_deref();
+                    #[cfg(not(feature = "no_module"))]
+                    let print = global.print.as_deref().or(print);
+
+                    if let Some(print) = print {
                         let text = result.into_immutable_string().map_err(|typ| {
                             let t = self.map_type_name(type_name::<ImmutableString>()).into();
                             ERR::ErrorMismatchOutputType(t, typ.into(), pos)
@@ -467,7 +486,11 @@ impl Engine {
                     (Dynamic::UNIT, false)
                 }
                 KEYWORD_DEBUG => {
-                    if let Some(ref debug) = self.debug {
+                    let debug = self.debug.as_deref();
+                    #[cfg(not(feature = "no_module"))]
+                    let debug = global.debug.as_deref().or(debug);
+
+                    if let Some(debug) = debug {
                         let text = result.into_immutable_string().map_err(|typ| {
                             let t = self.map_type_name(type_name::<ImmutableString>()).into();
                             ERR::ErrorMismatchOutputType(t, typ.into(), pos)
@@ -1477,9 +1500,11 @@ impl Engine {
         }
 
         // Search for the root namespace
-        let module = self
-            .search_imports(global, namespace)
-            .ok_or_else(|| ERR::ErrorModuleNotFound(namespace.to_string(), namespace.position()))?;
+        let module = match self.search_imports(global, namespace) {
+            Some(module) => Some(module),
+            None => self.search_deferred_imports(global, scope, namespace)?,
+        }
+        .ok_or_else(|| ERR::ErrorModuleNotFound(namespace.to_string(), namespace.position()))?;
 
         // First search script-defined functions in namespace (can override built-in)
         let mut func = module.get_qualified_fn(hash).or_else(|| {
@@ -1524,6 +1549,14 @@ impl Engine {
             }
         }
 
+        // Then check variadic functions in the namespace
+        if func.is_none() {
+            func = namespace.path[1..]
+                .iter()
+                .try_fold(&*module, |m, p| m.get_sub_module(&p.name))
+                .and_then(|m| m.get_var_args_fn(fn_name));
+        }
+
         // Clone first argument if the function is not a method after-all
         if !func.map_or(true, RhaiFunc::is_method) {
             if let Some(first) = first_arg_value {
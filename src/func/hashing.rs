@@ -56,7 +56,14 @@ impl BuildHasher for StraightHasherBuilder {
 #[inline(always)]
 #[must_use]
 pub fn get_hasher() -> ahash::AHasher {
-    match config::hashing::get_hashing_seed() {
+    get_hasher_with_seed(config::hashing::get_hashing_seed().as_ref())
+}
+
+/// Create an instance of the default hasher with a specific hashing seed.
+#[inline(always)]
+#[must_use]
+fn get_hasher_with_seed(seed: Option<&[u64; 4]>) -> ahash::AHasher {
+    match seed {
         Some([seed1, seed2, seed3, seed4]) if (seed1 | seed2 | seed3 | seed4) != 0 => {
             ahash::RandomState::with_seeds(*seed1, *seed2, *seed3, *seed4).build_hasher()
         }
@@ -113,8 +120,70 @@ pub fn calc_fn_hash<'a>(
     fn_name: &str,
     num: usize,
 ) -> u64 {
-    let s = &mut get_hasher();
+    hash_fn(&mut get_hasher(), namespace, fn_name, num)
+}
 
+/// Calculate a [`u64`] hash key from a namespace-qualified function name and the number of
+/// parameters, using a specific hashing seed instead of the global one.
+///
+/// Module names making up the namespace are passed in via `&str` references from an iterator.
+/// If the function is not namespace-qualified, pass [`None`] as the namespace.
+///
+/// The result is the same as the hash key used internally by Rhai when the global hashing seed
+/// (see [`set_hashing_seed`][crate::config::hashing::set_hashing_seed]) is `seed`.  For a fixed
+/// non-zero seed, the result is deterministic across runs and processes (on the same platform
+/// and for the same version of Rhai), so it can be stored in persistent caches.
+///
+/// # Note
+///
+/// The first module name is skipped.  Hashing starts from the _second_ module in the chain.
+///
+/// # Native Rust Functions
+///
+/// This is the hash key of script-defined functions, and only the _base_ of the hash key of native
+/// Rust functions.  The full hash key of a native Rust function also includes the
+/// [`TypeId`]'s of its parameter types, which are not guaranteed to be the same across different
+/// builds of the same program (e.g. with a different compiler version), so it cannot be stored
+/// in persistent caches.
+///
+/// # Example
+///
+/// ```
+/// use rhai::config::hashing::calc_fn_hash_with_seed;
+///
+/// let seed = [1, 2, 3, 4];
+///
+/// let hash = calc_fn_hash_with_seed(None, "foo", 2, seed);
+///
+/// assert_eq!(hash, calc_fn_hash_with_seed(None, "foo", 2, seed));
+/// assert_ne!(hash, calc_fn_hash_with_seed(None, "foo", 3, seed));
+/// assert_ne!(hash, calc_fn_hash_with_seed(None, "foo", 2, [5, 6, 7, 8]));
+/// ```
+#[inline]
+#[must_use]
+pub fn calc_fn_hash_with_seed<'a>(
+    namespace: impl IntoIterator<Item = &'a str>,
+    fn_name: &str,
+    num: usize,
+    seed: [u64; 4],
+) -> u64 {
+    hash_fn(
+        &mut get_hasher_with_seed(Some(&seed)),
+        namespace,
+        fn_name,
+        num,
+    )
+}
+
+/// Hash a namespace-qualified function name and the number of parameters.
+#[inline]
+#[must_use]
+fn hash_fn<'a>(
+    s: &mut ahash::AHasher,
+    namespace: impl IntoIterator<Item = &'a str>,
+    fn_name: &str,
+    num: usize,
+) -> u64 {
     s.write_u8(b'F'); // hash a discriminant
 
     let mut count = 0;
@@ -25,13 +25,16 @@ pub use function::RhaiFunc;
 #[cfg(not(feature = "no_object"))]
 #[cfg(not(feature = "no_function"))]
 pub use hashing::calc_typed_method_hash;
-pub use hashing::{calc_fn_hash, calc_fn_hash_full, calc_var_hash, get_hasher, StraightHashMap};
+pub use hashing::{
+    calc_fn_hash, calc_fn_hash_full, calc_fn_hash_with_seed, calc_var_hash, get_hasher,
+    StraightHashMap,
+};
 #[cfg(feature = "internals")]
 #[allow(deprecated)]
 pub use native::NativeCallContextStore;
 #[allow(unused_imports)]
 pub use native::{
     locked_read, locked_write, shared_get_mut, shared_make_mut, shared_take, shared_take_or_clone,
-    FnIterator, Locked, NativeCallContext, SendSync, Shared,
+    FnDisplay, FnIterator, Locked, NativeCallContext, SendSync, Shared,
 };
 pub use register::RhaiNativeFunc;
@@ -594,6 +594,13 @@ pub type FnBuiltin = (
     bool,
 );
 
+/// Function that formats a value of a particular type into text for display.
+#[cfg(not(feature = "sync"))]
+pub type FnDisplay = dyn Fn(&Dynamic) -> String;
+/// Function that formats a value of a particular type into text for display.
+#[cfg(feature = "sync")]
+pub type FnDisplay = dyn Fn(&Dynamic) -> String + Send + Sync;
+
 /// Function that gets an iterator from a type.
 #[cfg(not(feature = "sync"))]
 pub type FnIterator = dyn Fn(Dynamic) -> Box<dyn Iterator<Item = RhaiResultOf<Dynamic>>>;
@@ -682,6 +689,38 @@ pub type OnVarCallback = dyn Fn(&str, usize, EvalContext) -> RhaiResultOf<Option
 pub type OnVarCallback =
     dyn Fn(&str, usize, EvalContext) -> RhaiResultOf<Option<Dynamic>> + Send + Sync;
 
+/// Callback function for reading a [module][crate::Module] variable.
+#[cfg(not(feature = "sync"))]
+pub type OnModuleVarReadCallback = dyn Fn(&str) -> Option<Dynamic>;
+/// Callback function for reading a [module][crate::Module] variable.
+#[cfg(feature = "sync")]
+pub type OnModuleVarReadCallback = dyn Fn(&str) -> Option<Dynamic> + Send + Sync;
+
+/// Callback function for resolving a variable defined in both [modules][crate::Module] being merged.
+#[cfg(not(feature = "sync"))]
+pub type OnMergeVarCallback = dyn Fn(&str, &Dynamic, &Dynamic) -> Dynamic;
+/// Callback function for resolving a variable defined in both [modules][crate::Module] being merged.
+#[cfg(feature = "sync")]
+pub type OnMergeVarCallback = dyn Fn(&str, &Dynamic, &Dynamic) -> Dynamic + Send + Sync;
+
+/// Callback function for lazily constructing a [module][crate::Module].
+#[cfg(not(feature = "sync"))]
+#[cfg(not(feature = "no_module"))]
+pub type OnBuildModuleCallback = dyn Fn() -> crate::Module;
+/// Callback function for lazily constructing a [module][crate::Module].
+#[cfg(feature = "sync")]
+#[cfg(not(feature = "no_module"))]
+pub type OnBuildModuleCallback = dyn Fn() -> crate::Module + Send + Sync;
+
+/// Callback function for observing a module `import`.
+#[cfg(not(feature = "sync"))]
+#[cfg(not(feature = "no_module"))]
+pub type OnImportCallback = dyn Fn(&str, &str) -> RhaiResultOf<()>;
+/// Callback function for observing a module `import`.
+#[cfg(feature = "sync")]
+#[cfg(not(feature = "no_module"))]
+pub type OnImportCallback = dyn Fn(&str, &str) -> RhaiResultOf<()> + Send + Sync;
+
 /// Callback function for variable definition.
 #[cfg(not(feature = "sync"))]
 pub type OnDefVarCallback = dyn Fn(bool, VarDefInfo, EvalContext) -> RhaiResultOf<bool>;
@@ -43,6 +43,15 @@ impl Engine {
             return Err(ERR::ErrorStackOverflow(pos).into());
         }
 
+        global.num_script_fn_calls += 1;
+
+        // Guard against too many function calls
+        #[cfg(not(feature = "unchecked"))]
+        if self.max_script_fn_calls() > 0 && global.num_script_fn_calls > self.max_script_fn_calls()
+        {
+            return Err(ERR::ErrorTooManyFunctionCalls(pos).into());
+        }
+
         #[cfg(feature = "debugging")]
         if self.debugger_interface.is_none() && fn_def.body.is_empty() {
             return Ok(Dynamic::UNIT);
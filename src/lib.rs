@@ -242,14 +242,16 @@ pub use eval::EvalContext;
 use func::calc_typed_method_hash;
 use func::{calc_fn_hash, calc_fn_hash_full, calc_var_hash};
 pub use func::{plugin, FuncArgs, NativeCallContext, RhaiNativeFunc};
-pub use module::{FnNamespace, FuncRegistration, Module};
+pub use module::{
+    FnNamespace, FuncMetadata, FuncRegistration, MergeStrategy, Module, ModuleVisitor,
+};
 pub use packages::string_basic::{FUNC_TO_DEBUG, FUNC_TO_STRING};
 pub use rhai_codegen::*;
 #[cfg(not(feature = "no_time"))]
 pub use types::Instant;
 pub use types::{
-    Dynamic, EvalAltResult, FnPtr, ImmutableString, LexError, ParseError, ParseErrorType, Position,
-    Scope, VarDefInfo,
+    fail_with, Dynamic, EvalAltResult, FnPtr, ImmutableString, LexError, ParseError,
+    ParseErrorType, Position, Scope, ScopeSnapshot, VarDefInfo,
 };
 
 /// _(debugging)_ Module containing types for debugging.
@@ -289,6 +291,8 @@ pub use ast::ScriptFnMetadata;
 #[cfg(not(feature = "no_function"))]
 pub use api::call_fn::CallFnOptions;
 
+pub use api::compile::CompileOptions;
+
 /// Variable-sized array of [`Dynamic`] values.
 ///
 /// Not available under `no_index`.
@@ -4,7 +4,8 @@
 use crate::api::formatting::format_param_type_for_display;
 use crate::ast::FnAccess;
 use crate::func::{
-    shared_take_or_clone, FnIterator, RhaiFunc, RhaiNativeFunc, SendSync, StraightHashMap,
+    get_hasher, shared_take_or_clone, FnDisplay, FnIterator, RhaiFunc, RhaiNativeFunc, SendSync,
+    StraightHashMap,
 };
 use crate::types::{dynamic::Variant, BloomFilterU64, CustomTypeInfo, CustomTypesCollection};
 use crate::{
@@ -20,8 +21,9 @@ use std::collections::hash_map::Entry;
 use std::prelude::v1::*;
 use std::{
     any::{type_name, TypeId},
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     fmt,
+    hash::{Hash, Hasher},
     ops::{Add, AddAssign},
 };
 
@@ -69,6 +71,52 @@ impl FnNamespace {
     }
 }
 
+/// How to handle variables, functions and sub-modules that are defined in both [modules][Module]
+/// when merging them with [`Module::merge_with_strategy`].
+#[derive(Clone)]
+#[non_exhaustive]
+pub enum MergeStrategy {
+    /// Entries in the other [`Module`] replace those in this [`Module`].
+    ///
+    /// This is the behavior of [`Module::merge`].
+    Overwrite,
+    /// Entries in this [`Module`] are kept.
+    KeepExisting,
+    /// Do not merge anything if there are entries defined in both [modules][Module].
+    Error,
+    /// Call a function with the name of the variable, the existing value and the new value to
+    /// compute the merged value of a variable defined in both [modules][Module].
+    ///
+    /// Functions in the other [`Module`] replace those in this [`Module`].
+    /// Sub-modules defined in both [modules][Module] are merged with the same strategy.
+    Custom(Shared<crate::func::native::OnMergeVarCallback>),
+}
+
+impl fmt::Debug for MergeStrategy {
+    #[cold]
+    #[inline(never)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overwrite => f.write_str("Overwrite"),
+            Self::KeepExisting => f.write_str("KeepExisting"),
+            Self::Error => f.write_str("Error"),
+            Self::Custom(..) => f.write_str("Custom(<fn>)"),
+        }
+    }
+}
+
+impl MergeStrategy {
+    /// Create a [`MergeStrategy::Custom`] from a function that computes the merged value of a
+    /// variable from its name, the existing value and the new value.
+    #[inline(always)]
+    #[must_use]
+    pub fn custom(
+        resolve: impl Fn(&str, &Dynamic, &Dynamic) -> Dynamic + SendSync + 'static,
+    ) -> Self {
+        Self::Custom(Shared::new(resolve))
+    }
+}
+
 /// A type containing the metadata of a single registered function.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[non_exhaustive]
@@ -85,6 +133,8 @@ pub struct FuncMetadata {
     pub num_params: usize,
     /// Parameter types (if applicable).
     pub param_types: FnArgsVec<TypeId>,
+    /// Return type (if known).
+    pub return_type_id: Option<TypeId>,
     /// Parameter names and types (if available).
     #[cfg(feature = "metadata")]
     pub params_info: FnArgsVec<Identifier>,
@@ -96,6 +146,37 @@ pub struct FuncMetadata {
     pub comments: crate::StaticVec<SmartString>,
 }
 
+/// Calculate the [`u64`] hash key of a native Rust function taking any number of arguments.
+///
+/// The number of parameters is hashed as [`usize::MAX`], which no fixed-arity function can have.
+#[inline(always)]
+#[must_use]
+fn calc_var_args_fn_hash(name: &str) -> u64 {
+    calc_fn_hash(None, name, usize::MAX)
+}
+
+/// Get the name of a standard type from its [`TypeId`].
+#[must_use]
+fn std_type_name_of(type_id: TypeId) -> Option<&'static str> {
+    macro_rules! check {
+        ($($t:ty),*) => {
+            $(if type_id == TypeId::of::<$t>() {
+                return Some(type_name::<$t>());
+            })*
+        };
+    }
+
+    check!(crate::INT, bool, char, ImmutableString, crate::FnPtr, ());
+    #[cfg(not(feature = "no_float"))]
+    check!(crate::FLOAT);
+    #[cfg(not(feature = "no_index"))]
+    check!(crate::Array, crate::Blob);
+    #[cfg(not(feature = "no_object"))]
+    check!(crate::Map);
+
+    None
+}
+
 impl FuncMetadata {
     /// _(metadata)_ Generate a signature of the function.
     /// Exported under the `metadata` feature only.
@@ -221,6 +302,7 @@ impl FuncRegistration {
                 access: FnAccess::Public,
                 num_params: 0,
                 param_types: <_>::default(),
+                return_type_id: None,
                 #[cfg(feature = "metadata")]
                 params_info: <_>::default(),
                 #[cfg(feature = "metadata")]
@@ -465,6 +547,7 @@ impl FuncRegistration {
         let mut reg = self;
         reg.purity = None;
         reg.volatility = None;
+        reg.metadata.return_type_id = Some(Module::map_type(true, TypeId::of::<R>()));
 
         reg.set_into_module_raw(module, FUNC::param_types(), func)
     }
@@ -488,6 +571,17 @@ impl FuncRegistration {
         module: &mut Module,
         arg_types: impl AsRef<[TypeId]>,
         func: RhaiFunc,
+    ) -> &FuncMetadata {
+        self.set_into_module_with_hash(module, None, arg_types, func)
+    }
+    /// Register the function into the specified [`Module`] under a particular hash key, or the
+    /// hash key calculated from the function name and parameter types if [`None`].
+    fn set_into_module_with_hash(
+        self,
+        module: &mut Module,
+        hash: Option<u64>,
+        arg_types: impl AsRef<[TypeId]>,
+        func: RhaiFunc,
     ) -> &FuncMetadata {
         // Make sure that conflicting flags should not be set.
         debug_assert!(self.purity.is_none());
@@ -547,7 +641,8 @@ impl FuncRegistration {
         }
 
         let hash_base = calc_fn_hash(None, &f.name, f.param_types.len());
-        let hash_fn = calc_fn_hash_full(hash_base, f.param_types.iter().copied());
+        let hash_fn =
+            hash.unwrap_or_else(|| calc_fn_hash_full(hash_base, f.param_types.iter().copied()));
         f.hash = hash_fn;
 
         // Catch hash collisions in testing environment only.
@@ -583,6 +678,31 @@ impl FuncRegistration {
     }
 }
 
+/// A visitor over all the members of a [`Module`], including those inside sub-modules.
+///
+/// All methods default to doing nothing, so only the events of interest need to be implemented.
+///
+/// Use [`Module::accept`] to walk a [`Module`] with a visitor.
+pub trait ModuleVisitor {
+    /// Visit a variable.
+    ///
+    /// `path` holds the names of the sub-modules leading from the root [`Module`] to the
+    /// [`Module`] containing the variable.  It is empty for variables in the root [`Module`].
+    #[allow(unused_variables)]
+    fn visit_var(&mut self, path: &[&str], name: &str, value: &Dynamic) {}
+    /// Visit a function (either native Rust or script-defined).
+    ///
+    /// `path` holds the names of the sub-modules leading from the root [`Module`] to the
+    /// [`Module`] containing the function.  It is empty for functions in the root [`Module`].
+    #[allow(unused_variables)]
+    fn visit_fn(&mut self, path: &[&str], metadata: &FuncMetadata) {}
+    /// Called before the members of a sub-module are visited.
+    #[allow(unused_variables)]
+    fn enter_sub_module(&mut self, name: &str) {}
+    /// Called after all the members of a sub-module are visited.
+    fn exit_sub_module(&mut self) {}
+}
+
 bitflags! {
     /// Bit-flags containing all status for [`Module`].
     #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
@@ -595,11 +715,21 @@ bitflags! {
         const INDEXED = 0b0000_0100;
         /// Does the [`Module`] contain indexed functions that have been exposed to the global namespace?
         const INDEXED_GLOBAL_FUNCTIONS = 0b0000_1000;
+        /// Does the [`Module`], or any sub-module indexed into it, have a variable read callback?
+        const VAR_READ_HOOKS = 0b0001_0000;
     }
 }
 
 /// A module which may contain variables, sub-modules, external Rust functions,
 /// and/or script-defined functions.
+///
+/// # Thread Safety
+///
+/// Under the `sync` feature, a [`Module`] is always [`Send`] `+` [`Sync`] and can be shared
+/// across threads (e.g. as a [`Shared`]`<Module>`).  This is checked at compile time: all functions
+/// and callbacks must be [`Send`] `+` [`Sync`], and so must all values held in variables, because
+/// [`Dynamic`] only accepts such values under `sync`.  An [`Rc`][std::rc::Rc]-backed value
+/// therefore cannot be stored into a [`Module`] meant to cross threads.
 #[derive(Clone)]
 pub struct Module {
     /// ID identifying the module.
@@ -620,16 +750,34 @@ pub struct Module {
     /// Flattened collection of all functions, native Rust and scripted.
     /// including those in sub-modules.
     all_functions: Option<StraightHashMap<RhaiFunc>>,
+    /// Native Rust functions taking any number of arguments, keyed by name.
+    var_args_functions: BTreeMap<Identifier, RhaiFunc>,
     /// Bloom filter on native Rust functions (in scripted hash format) that contain [`Dynamic`] parameters.
     dynamic_functions_filter: BloomFilterU64,
     /// Iterator functions, keyed by the type producing the iterator.
     type_iterators: BTreeMap<TypeId, Shared<FnIterator>>,
     /// Flattened collection of iterator functions, including those in sub-modules.
     all_type_iterators: BTreeMap<TypeId, Shared<FnIterator>>,
+    /// Display functions, keyed by the type they format.
+    display_fns: BTreeMap<TypeId, Shared<FnDisplay>>,
+    /// Host metadata attached to the [`Module`], not visible to scripts.
+    metadata: BTreeMap<Identifier, Dynamic>,
+    /// Callback intercepting reads of [`Module`] variables.
+    on_var_read: Option<Shared<crate::func::native::OnModuleVarReadCallback>>,
+    /// Custom operators defined by the [`Module`], with their precedences.
+    #[cfg(not(feature = "no_custom_syntax"))]
+    custom_operators: BTreeMap<Identifier, crate::engine::Precedence>,
     /// Flags.
     flags: ModuleFlags,
 }
 
+// Under `sync`, a module must be safe to share across threads.
+#[cfg(feature = "sync")]
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Module>();
+};
+
 impl Default for Module {
     #[inline(always)]
     #[must_use]
@@ -658,6 +806,8 @@ impl fmt::Debug for Module {
                     .collect::<Vec<_>>(),
             )
             .field("vars", &self.variables)
+            .field("metadata", &self.metadata)
+            .field("on_var_read", &self.on_var_read.is_some())
             .field(
                 "functions",
                 &self
@@ -670,8 +820,18 @@ impl fmt::Debug for Module {
                     })
                     .collect::<Vec<_>>(),
             )
+            .field(
+                "var_args_functions",
+                &self
+                    .var_args_functions
+                    .keys()
+                    .map(SmartString::as_str)
+                    .collect::<Vec<_>>(),
+            )
             .field("flags", &self.flags);
 
+        #[cfg(not(feature = "no_custom_syntax"))]
+        d.field("custom_operators", &self.custom_operators);
         #[cfg(feature = "metadata")]
         d.field("doc", &self.doc);
 
@@ -756,9 +916,15 @@ impl Module {
             all_variables: None,
             functions: None,
             all_functions: None,
+            var_args_functions: BTreeMap::new(),
             dynamic_functions_filter: BloomFilterU64::new(),
             type_iterators: BTreeMap::new(),
             all_type_iterators: BTreeMap::new(),
+            display_fns: BTreeMap::new(),
+            metadata: BTreeMap::new(),
+            on_var_read: None,
+            #[cfg(not(feature = "no_custom_syntax"))]
+            custom_operators: BTreeMap::new(),
             flags: ModuleFlags::INDEXED,
         }
     }
@@ -880,6 +1046,58 @@ impl Module {
         self
     }
 
+    /// Get a piece of host metadata attached to the [`Module`], if any.
+    ///
+    /// Host metadata is not visible to scripts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module = Module::new();
+    /// module.set_metadata("source_url", "https://example.com/hello.rhai");
+    /// assert_eq!(
+    ///     module.get_metadata("source_url").unwrap().to_string(),
+    ///     "https://example.com/hello.rhai"
+    /// );
+    /// assert!(module.get_metadata("signature").is_none());
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn get_metadata(&self, key: &str) -> Option<&Dynamic> {
+        self.metadata.get(key)
+    }
+    /// Attach a piece of host metadata to the [`Module`].
+    ///
+    /// Host metadata is not visible to scripts.  It is typically used by a
+    /// [module resolver][crate::ModuleResolver] to annotate a resolved [`Module`] with
+    /// information such as its provenance, for the host to read later.
+    ///
+    /// If there is an existing value under the same key, it is replaced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module = Module::new();
+    /// module.set_metadata("timestamp", 42_i64);
+    /// assert_eq!(module.get_metadata("timestamp").unwrap().as_int().unwrap(), 42);
+    /// ```
+    #[inline]
+    pub fn set_metadata(
+        &mut self,
+        key: impl Into<Identifier>,
+        value: impl Variant + Clone,
+    ) -> &mut Self {
+        self.metadata.insert(key.into(), Dynamic::from(value));
+        self
+    }
+    /// Remove a piece of host metadata from the [`Module`], returning its value if any.
+    #[inline(always)]
+    pub fn remove_metadata(&mut self, key: &str) -> Option<Dynamic> {
+        self.metadata.remove(key)
+    }
+
     /// Clear the [`Module`].
     #[inline(always)]
     pub fn clear(&mut self) {
@@ -891,11 +1109,20 @@ impl Module {
         self.all_variables = None;
         self.functions = None;
         self.all_functions = None;
+        self.var_args_functions.clear();
         self.dynamic_functions_filter.clear();
         self.type_iterators.clear();
         self.all_type_iterators.clear();
-        self.flags
-            .remove(ModuleFlags::INDEXED | ModuleFlags::INDEXED_GLOBAL_FUNCTIONS);
+        self.display_fns.clear();
+        self.metadata.clear();
+        self.on_var_read = None;
+        #[cfg(not(feature = "no_custom_syntax"))]
+        self.custom_operators.clear();
+        self.flags.remove(
+            ModuleFlags::INDEXED
+                | ModuleFlags::INDEXED_GLOBAL_FUNCTIONS
+                | ModuleFlags::VAR_READ_HOOKS,
+        );
     }
 
     /// Map a custom type to a friendly display name.
@@ -1068,9 +1295,11 @@ impl Module {
                 .functions
                 .as_ref()
                 .map_or(true, StraightHashMap::is_empty)
+            && self.var_args_functions.is_empty()
             && self.variables.is_empty()
             && self.modules.is_empty()
             && self.type_iterators.is_empty()
+            && self.display_fns.is_empty()
             && self
                 .all_functions
                 .as_ref()
@@ -1180,6 +1409,59 @@ impl Module {
         self.get_var(name).and_then(Dynamic::try_cast::<T>)
     }
 
+    /// Get a reference to the value of a [`Module`] variable, without cloning it.
+    ///
+    /// Returns [`None`] if the variable does not exist, is not of type `T`, or is _shared_.
+    ///
+    /// Strings are stored as [`ImmutableString`][crate::ImmutableString], so `T` must be
+    /// [`ImmutableString`][crate::ImmutableString] (not [`String`]) to get a reference to one.
+    ///
+    /// Unlike [`get_var_value`][Module::get_var_value], this always returns the stored value and
+    /// does not call the callback set via [`on_var_read`][Module::on_var_read].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::{Module, ImmutableString};
+    /// let mut module = Module::new();
+    /// module.set_var("greeting", "hello, world!");
+    /// assert_eq!(module.get_var_ref::<ImmutableString>("greeting").expect("greeting should exist"), "hello, world!");
+    /// assert!(module.get_var_ref::<String>("greeting").is_none());
+    /// assert!(module.get_var_ref::<i64>("greeting").is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get_var_ref<T: Variant + Clone>(&self, name: &str) -> Option<&T> {
+        self.variables
+            .get(name)
+            .and_then(Dynamic::downcast_ref::<T>)
+    }
+
+    /// Get the type name of the value of a [`Module`] variable.
+    ///
+    /// This is the same as [`Dynamic::type_name`]: standard types have their Rhai names (e.g.
+    /// `i64`, `string`, `array`), while custom types have their full Rust type names.  Use
+    /// [`Engine::map_type_name`][crate::Engine::map_type_name] to get the friendly names of custom
+    /// types registered with an [`Engine`][crate::Engine].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module = Module::new();
+    /// module.set_var("answer", 42_i64);
+    /// module.set_var("name", "hello");
+    ///
+    /// assert_eq!(module.var_type_name("answer"), Some("i64"));
+    /// assert_eq!(module.var_type_name("name"), Some("string"));
+    /// assert_eq!(module.var_type_name("missing"), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn var_type_name(&self, name: &str) -> Option<&'static str> {
+        self.variables.get(name).map(Dynamic::type_name)
+    }
+
     /// Get a [`Module`] variable as a [`Dynamic`].
     ///
     /// # Example
@@ -1193,7 +1475,56 @@ impl Module {
     #[inline(always)]
     #[must_use]
     pub fn get_var(&self, name: &str) -> Option<Dynamic> {
-        self.variables.get(name).cloned()
+        self.read_var_hook(name)
+            .or_else(|| self.variables.get(name).cloned())
+    }
+
+    /// Set a callback that intercepts reads of [`Module`] variables by scripts and by
+    /// [`get_var`][Module::get_var].
+    ///
+    /// The callback is called with the name of the variable.  Returning `Some` overrides the stored
+    /// value (even if there is no such variable), while returning `None` falls back to the stored
+    /// value.
+    ///
+    /// This is useful for computed variables, or for auditing which variables are read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Dynamic, Engine, Module};
+    ///
+    /// let mut module = Module::new();
+    /// module.set_var("answer", 42_i64);
+    /// module.on_var_read(|name| match name {
+    ///     "now" => Some(Dynamic::from(123_i64)),
+    ///     _ => None,
+    /// });
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.register_static_module("m", module.into());
+    ///
+    /// assert_eq!(engine.eval::<i64>("m::now + m::answer")?, 165);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn on_var_read(
+        &mut self,
+        callback: impl Fn(&str) -> Option<Dynamic> + SendSync + 'static,
+    ) -> &mut Self {
+        self.on_var_read = Some(Shared::new(callback));
+        self.flags |= ModuleFlags::VAR_READ_HOOKS;
+        self
+    }
+
+    /// Call the variable read callback, if any.
+    #[inline(always)]
+    #[must_use]
+    fn read_var_hook(&self, name: &str) -> Option<Dynamic> {
+        self.on_var_read
+            .as_ref()
+            .and_then(|callback| callback(name))
     }
 
     /// Set a variable into the [`Module`].
@@ -1239,10 +1570,214 @@ impl Module {
         self
     }
 
+    /// Set a variable holding an [`Array`][crate::Array] into the [`Module`], converting each item
+    /// into a [`Dynamic`].
+    ///
+    /// Unlike [`set_var`][Module::set_var] with a [`Vec`], which stores the [`Vec`] itself as a
+    /// custom type, scripts see the variable as an array.
+    ///
+    /// If there is an existing variable of the same name, it is replaced.
+    ///
+    /// Not available under `no_index`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::{Array, Module};
+    /// let mut module = Module::new();
+    /// module.set_var_array("primes", vec![2_i64, 3, 5, 7]);
+    /// assert_eq!(module.get_var_value::<Array>("primes").expect("primes should exist").len(), 4);
+    /// ```
+    #[cfg(not(feature = "no_index"))]
+    #[inline]
+    pub fn set_var_array<T: Into<Dynamic>>(
+        &mut self,
+        name: impl Into<Identifier>,
+        values: impl IntoIterator<Item = T>,
+    ) -> &mut Self {
+        let array: crate::Array = values.into_iter().map(Into::into).collect();
+        self.set_var(name, array)
+    }
+
+    /// Set a variable holding an object [`Map`][crate::Map] into the [`Module`], converting each
+    /// value into a [`Dynamic`].
+    ///
+    /// Unlike [`set_var`][Module::set_var] with a [`HashMap`][std::collections::HashMap], which
+    /// stores the [`HashMap`][std::collections::HashMap] itself as a custom type, scripts see the
+    /// variable as an object map.
+    ///
+    /// If there is an existing variable of the same name, it is replaced.
+    ///
+    /// Not available under `no_object`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use rhai::{Map, Module};
+    /// let mut config = HashMap::new();
+    /// config.insert("retries", 3_i64);
+    /// config.insert("timeout", 30_i64);
+    ///
+    /// let mut module = Module::new();
+    /// module.set_var_map("config", config);
+    /// assert_eq!(module.get_var_value::<Map>("config").expect("config should exist").len(), 2);
+    /// ```
+    #[cfg(not(feature = "no_object"))]
+    #[inline]
+    pub fn set_var_map<K: Into<Identifier>, T: Into<Dynamic>>(
+        &mut self,
+        name: impl Into<Identifier>,
+        values: impl IntoIterator<Item = (K, T)>,
+    ) -> &mut Self {
+        let map: crate::Map = values
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect();
+        self.set_var(name, map)
+    }
+
+    /// Remove a variable from the [`Module`], returning its value if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module = Module::new();
+    /// module.set_var("answer", 42_i64);
+    /// assert_eq!(module.remove_var("answer").unwrap().as_int().unwrap(), 42);
+    /// assert!(!module.contains_var("answer"));
+    /// ```
+    #[inline]
+    pub fn remove_var(&mut self, name: &str) -> Option<Dynamic> {
+        let value = self.variables.remove(name)?;
+        self.all_variables = None;
+        self.flags
+            .remove(ModuleFlags::INDEXED | ModuleFlags::INDEXED_GLOBAL_FUNCTIONS);
+        Some(value)
+    }
+
+    /// Set a batch of variables into the [`Module`] in one step, returning the previous values of
+    /// the variables that are replaced.
+    ///
+    /// Existing variables of the same names are replaced.  Variables that did not exist before
+    /// are not included in the returned map.
+    ///
+    /// Because all the updates are applied within a single mutable borrow, no script can observe
+    /// a partially-applied batch.  Passing the returned map back to this method rolls the
+    /// replaced variables back to their previous values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::{Dynamic, Module};
+    /// let mut module = Module::new();
+    /// module.set_var("retries", 3_i64);
+    ///
+    /// let previous = module.rebind_vars([
+    ///     ("retries", Dynamic::from("many")),
+    ///     ("timeout", Dynamic::from(30_i64)),
+    /// ]);
+    ///
+    /// assert_eq!(module.get_var_value::<String>("retries").unwrap(), "many");
+    /// assert_eq!(module.get_var_value::<i64>("timeout").unwrap(), 30);
+    /// assert_eq!(previous.len(), 1);
+    /// assert_eq!(previous["retries"].as_int().unwrap(), 3);
+    ///
+    /// module.rebind_vars(previous);
+    /// assert_eq!(module.get_var_value::<i64>("retries").unwrap(), 3);
+    /// ```
+    pub fn rebind_vars<S: Into<Identifier>>(
+        &mut self,
+        updates: impl IntoIterator<Item = (S, Dynamic)>,
+    ) -> BTreeMap<Identifier, Dynamic> {
+        let mut previous = BTreeMap::new();
+
+        for (name, value) in updates {
+            let name = name.into();
+
+            if let Some(old) = self.variables.remove(&name) {
+                previous.entry(name.clone()).or_insert(old);
+            }
+
+            self.set_var(name, value);
+        }
+
+        previous
+    }
+
+    /// Set a table of named constants of the same type into the [`Module`], together with a
+    /// reverse-lookup function, `name_of`, which returns the name of a value in the table
+    /// (or `()` if the value is not found).
+    ///
+    /// This is useful for exposing an enum-like group of constants.
+    ///
+    /// Existing variables of the same names are replaced.
+    /// Calling this method again with the same type replaces the `name_of` function for that type,
+    /// which then only covers the new table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::{Engine, Module};
+    ///
+    /// let mut module = Module::new();
+    /// module.set_const_table(&[("RED", 1_i64), ("GREEN", 2), ("BLUE", 3)]);
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.register_static_module("color", module.into());
+    ///
+    /// assert_eq!(engine.eval::<i64>("color::GREEN").unwrap(), 2);
+    /// assert_eq!(engine.eval::<String>("color::name_of(color::BLUE)").unwrap(), "BLUE");
+    /// assert_eq!(engine.eval::<()>("color::name_of(42)").unwrap(), ());
+    /// ```
+    pub fn set_const_table<T: Variant + Clone + PartialEq>(
+        &mut self,
+        entries: &[(&str, T)],
+    ) -> &mut Self {
+        let table: Vec<(ImmutableString, T)> = entries
+            .iter()
+            .map(|(name, value)| ((*name).into(), value.clone()))
+            .collect();
+
+        for (name, value) in &table {
+            self.set_var(name.as_str(), value.clone());
+        }
+
+        self.set_native_fn("name_of", move |value: T| {
+            Ok(table
+                .iter()
+                .find(|(_, v)| *v == value)
+                .map_or(Dynamic::UNIT, |(name, _)| name.clone().into()))
+        });
+
+        self
+    }
+
     /// Get a namespace-qualified [`Module`] variable as a [`Dynamic`].
+    ///
+    /// The path leads from this [`Module`] to the sub-module holding the variable, if any, and is
+    /// used to find the variable read callback to call.
     #[cfg(not(feature = "no_module"))]
     #[inline]
-    pub(crate) fn get_qualified_var(&self, hash_var: u64) -> Option<Dynamic> {
+    pub(crate) fn get_qualified_var(
+        &self,
+        hash_var: u64,
+        path: &[crate::ast::Ident],
+        name: &str,
+    ) -> Option<Dynamic> {
+        // Only look for the sub-module if there is any variable read callback to call
+        if !self.is_indexed() || self.flags.intersects(ModuleFlags::VAR_READ_HOOKS) {
+            let value = path
+                .iter()
+                .try_fold(self, |m, p| m.modules.get(p.name.as_str()).map(|m| &**m))
+                .and_then(|m| m.read_var_hook(name));
+
+            if value.is_some() {
+                return value;
+            }
+        }
+
         self.all_variables
             .as_ref()
             .and_then(|c| c.get(&hash_var).cloned())
@@ -1288,6 +1823,7 @@ impl Module {
             access: fn_def.access,
             num_params,
             param_types: FnArgsVec::new_const(),
+            return_type_id: None,
             #[cfg(feature = "metadata")]
             params_info: fn_def.params.iter().map(Into::into).collect(),
             #[cfg(feature = "metadata")]
@@ -1325,6 +1861,39 @@ impl Module {
         })
     }
 
+    /// Get the position of the definition of a script-defined function in the [`Module`] based
+    /// on name and number of parameters.
+    ///
+    /// Together with the [`id`][Module::id] of the [`Module`] (usually the path of the script),
+    /// this locates the function definition in its source.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Module, Scope};
+    ///
+    /// let engine = Engine::new();
+    /// let ast = engine.compile("let x = 1;\nfn foo(x) { x }")?;
+    /// let module = Module::eval_ast_as_new(Scope::new(), &ast, &engine)?;
+    ///
+    /// # #[cfg(not(feature = "no_position"))]
+    /// assert_eq!(module.get_script_fn_position("foo", 1).unwrap().line(), Some(2));
+    /// assert!(module.get_script_fn_position("foo", 2).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "no_function"))]
+    #[inline]
+    #[must_use]
+    pub fn get_script_fn_position(
+        &self,
+        name: impl AsRef<str>,
+        num_params: usize,
+    ) -> Option<crate::Position> {
+        self.get_script_fn(name, num_params).map(|f| f.pos)
+    }
+
     /// Get a mutable reference to the underlying [`BTreeMap`] of sub-modules,
     /// creating one if empty.
     ///
@@ -1406,6 +1975,28 @@ impl Module {
         self
     }
 
+    /// Remove a sub-module from the [`Module`], returning it if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module = Module::new();
+    /// module.set_sub_module("question", Module::new());
+    /// assert!(module.remove_sub_module("question").is_some());
+    /// assert!(!module.contains_sub_module("question"));
+    /// ```
+    #[inline]
+    pub fn remove_sub_module(&mut self, name: &str) -> Option<SharedModule> {
+        let sub_module = self.modules.remove(name)?;
+        self.all_functions = None;
+        self.all_variables = None;
+        self.all_type_iterators.clear();
+        self.flags
+            .remove(ModuleFlags::INDEXED | ModuleFlags::INDEXED_GLOBAL_FUNCTIONS);
+        Some(sub_module)
+    }
+
     /// Does the particular Rust function exist in the [`Module`]?
     ///
     /// The [`u64`] hash is returned by the [`set_native_fn`][Module::set_native_fn] call.
@@ -1424,6 +2015,304 @@ impl Module {
         self.functions
             .as_ref()
             .map_or(false, |m| m.contains_key(&hash_fn))
+            || self
+                .var_args_functions
+                .keys()
+                .any(|name| calc_var_args_fn_hash(name) == hash_fn)
+    }
+
+    /// Does a function with a particular name and number of parameters exist in the [`Module`]?
+    ///
+    /// Parameter types are not checked, so any function with a matching name and number of
+    /// parameters counts, including script-defined functions.  A native Rust function taking any
+    /// number of arguments (see [`set_fn_var_args`][Module::set_fn_var_args]) matches any number
+    /// of parameters.
+    ///
+    /// Sub-modules are not searched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module = Module::new();
+    /// module.set_native_fn("calc", |x: i64, y: i64| Ok(x + y));
+    /// assert!(module.contains_fn_by_name("calc", 2));
+    /// assert!(!module.contains_fn_by_name("calc", 1));
+    /// assert!(!module.contains_fn_by_name("add", 2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains_fn_by_name(&self, name: &str, num_params: usize) -> bool {
+        self.iter_fn()
+            .any(|(.., f)| f.name == name && f.num_params == num_params)
+            || self.var_args_functions.contains_key(name)
+    }
+
+    /// Get the parameter types of a native Rust function in the [`Module`].
+    ///
+    /// The [`u64`] hash is returned by the [`set_native_fn`][Module::set_native_fn] call.
+    ///
+    /// Parameter types are recorded as registered, except that `&str` and [`String`] parameters
+    /// are recorded as [`ImmutableString`] and `&mut` parameters as the underlying type.
+    ///
+    /// Script-defined functions have no parameter types recorded, so the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::any::TypeId;
+    /// use rhai::{ImmutableString, Module, INT};
+    ///
+    /// let mut module = Module::new();
+    /// let hash = module.set_native_fn("calc", |x: INT, s: &str| Ok(x + s.len() as INT));
+    ///
+    /// assert_eq!(
+    ///     module.fn_param_types(hash),
+    ///     Some(&[TypeId::of::<INT>(), TypeId::of::<ImmutableString>()][..])
+    /// );
+    /// assert_eq!(module.fn_param_types(42), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn fn_param_types(&self, hash_fn: u64) -> Option<&[TypeId]> {
+        self.functions
+            .as_ref()
+            .and_then(|m| m.get(&hash_fn))
+            .map(|(_, f)| f.param_types.as_slice())
+    }
+
+    /// Remove a function from the [`Module`], returning `true` if it existed.
+    ///
+    /// The [`u64`] hash is returned by the [`set_native_fn`][Module::set_native_fn] call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module = Module::new();
+    /// let hash = module.set_native_fn("calc", |x: i64| Ok(42 + x));
+    /// assert!(module.remove_fn(hash));
+    /// assert!(!module.contains_fn(hash));
+    /// assert!(!module.remove_fn(hash));
+    /// ```
+    #[inline]
+    pub fn remove_fn(&mut self, hash_fn: u64) -> bool {
+        let removed = self
+            .functions
+            .as_mut()
+            .map_or(false, |m| m.remove(&hash_fn).is_some());
+
+        let len = self.var_args_functions.len();
+        self.var_args_functions
+            .retain(|name, _| calc_var_args_fn_hash(name) != hash_fn);
+
+        if !removed && self.var_args_functions.len() == len {
+            return false;
+        }
+
+        self.all_functions = None;
+        self.flags
+            .remove(ModuleFlags::INDEXED | ModuleFlags::INDEXED_GLOBAL_FUNCTIONS);
+        true
+    }
+
+    /// Check that a list of arguments matches the signature of a function in the [`Module`].
+    ///
+    /// The [`u64`] hash is returned by the [`set_native_fn`][Module::set_native_fn] call.
+    ///
+    /// Each argument must have the same type as its parameter, unless the parameter takes any
+    /// [`Dynamic`] value. Only the number of arguments is checked for script-defined functions.
+    ///
+    /// # Errors
+    ///
+    /// * [`ErrorFunctionNotFound`][crate::EvalAltResult::ErrorFunctionNotFound] if the function
+    ///   does not exist.
+    ///
+    /// * [`ErrorInFunctionCall`][crate::EvalAltResult::ErrorInFunctionCall] wrapping an
+    ///   [`ErrorMismatchDataType`][crate::EvalAltResult::ErrorMismatchDataType] naming the first
+    ///   mismatched parameter, or the number of arguments if it is wrong.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::{Dynamic, Module};
+    /// let mut module = Module::new();
+    /// let hash = module.set_native_fn("calc", |x: i64, y: bool| Ok(if y { x } else { 0 }));
+    ///
+    /// assert!(module.check_args(hash, &[&Dynamic::from(42_i64), &Dynamic::TRUE]).is_ok());
+    /// assert!(module.check_args(hash, &[&Dynamic::TRUE, &Dynamic::TRUE]).is_err());
+    /// assert!(module.check_args(hash, &[&Dynamic::from(42_i64)]).is_err());
+    /// ```
+    pub fn check_args(&self, hash_fn: u64, args: &[&Dynamic]) -> RhaiResultOf<()> {
+        use crate::api::formatting::map_std_type_name;
+
+        let pos = crate::Position::NONE;
+
+        let f = self
+            .functions
+            .as_ref()
+            .and_then(|m| m.get(&hash_fn))
+            .map(|(_, f)| f)
+            .ok_or_else(|| crate::ERR::ErrorFunctionNotFound(hash_fn.to_string(), pos))?;
+
+        let mismatch = |expected: String, actual: String| {
+            let err = crate::ERR::ErrorMismatchDataType(expected, actual, pos);
+            crate::ERR::ErrorInFunctionCall(f.name.to_string(), String::new(), err.into(), pos)
+        };
+
+        if args.len() != f.num_params {
+            return Err(mismatch(
+                format!("{} argument(s)", f.num_params),
+                format!("{} argument(s)", args.len()),
+            )
+            .into());
+        }
+
+        for (i, (&type_id, arg)) in f.param_types.iter().zip(args).enumerate() {
+            if type_id == TypeId::of::<Dynamic>() || type_id == arg.type_id() {
+                continue;
+            }
+
+            let expected = std_type_name_of(type_id).map(|name| map_std_type_name(name, true));
+            #[cfg(feature = "metadata")]
+            let expected = expected.or_else(|| {
+                f.params_info
+                    .get(i)
+                    .and_then(|p| p.split_once(':'))
+                    .map(|(_, typ)| typ.trim())
+            });
+
+            return Err(mismatch(
+                expected.unwrap_or_default().to_string(),
+                format!("{} for parameter #{}", arg.type_name(), i + 1),
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Find all functions (native Rust or script-defined) in the [`Module`] whose name and number
+    /// of parameters satisfy a predicate, returning their [`u64`] hash keys.
+    ///
+    /// Functions in sub-modules are not searched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module = Module::new();
+    /// let hash = module.set_native_fn("on_event", |x: i64, y: i64| Ok(x + y));
+    /// module.set_native_fn("on_start", || Ok(()));
+    /// module.set_native_fn("calc", |x: i64, y: i64| Ok(x * y));
+    ///
+    /// let found = module.find_fn(|name, num_params| name.starts_with("on_") && num_params == 2);
+    /// assert_eq!(found, [hash]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn find_fn(&self, predicate: impl Fn(&str, usize) -> bool) -> Vec<u64> {
+        self.find_fn_metadata(predicate).map(|f| f.hash).collect()
+    }
+    /// Get an iterator over the metadata of all functions (native Rust or script-defined) in the
+    /// [`Module`] whose name and number of parameters satisfy a predicate.
+    ///
+    /// Functions in sub-modules are not searched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module = Module::new();
+    /// module.set_native_fn("calc", |x: i64| Ok(42 + x));
+    /// module.set_native_fn("calc", |x: i64, y: i64| Ok(x + y));
+    ///
+    /// let mut found = module
+    ///     .find_fn_metadata(|name, _| name == "calc")
+    ///     .map(|f| f.num_params)
+    ///     .collect::<Vec<_>>();
+    /// found.sort();
+    ///
+    /// assert_eq!(found, [1, 2]);
+    /// ```
+    #[inline]
+    pub fn find_fn_metadata(
+        &self,
+        predicate: impl Fn(&str, usize) -> bool,
+    ) -> impl Iterator<Item = &FuncMetadata> {
+        self.iter_fn()
+            .map(|(_, f)| f)
+            .filter(move |f| predicate(&f.name, f.num_params))
+    }
+
+    /// Replace the implementation of a native Rust function in the [`Module`], keeping its hash key
+    /// and metadata (name, namespace, access, parameter types etc.) intact.
+    ///
+    /// This is useful for decorating registered functions in place, e.g. with tracing.
+    ///
+    /// Whether the function takes its first argument by reference, its purity and its volatility
+    /// are also kept.  The new function always receives a [`NativeCallContext`][crate::NativeCallContext].
+    ///
+    /// Returns `false` (and the [`Module`] is not modified) if there is no native Rust function
+    /// with the hash key (e.g. if it is a script-defined function or an iterator).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::{Engine, Module};
+    ///
+    /// let mut module = Module::new();
+    /// let hash = module.set_native_fn("calc", |x: i64| Ok(x + 1));
+    ///
+    /// assert!(module.replace_fn(hash, |_, args| Ok((args[0].as_int().unwrap() * 2).into())));
+    /// assert!(!module.replace_fn(42, |_, _| Ok(().into())));
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.register_static_module("m", module.into());
+    ///
+    /// assert_eq!(engine.eval::<i64>("m::calc(21)").unwrap(), 42);
+    /// ```
+    pub fn replace_fn(
+        &mut self,
+        hash_fn: u64,
+        func: impl Fn(crate::NativeCallContext, &mut crate::func::FnCallArgs) -> crate::RhaiResult
+            + SendSync
+            + 'static,
+    ) -> bool {
+        let f = match self.functions.as_mut().and_then(|m| m.get_mut(&hash_fn)) {
+            Some((f, _)) if !f.is_script() && !f.is_iter() => f,
+            _ => return false,
+        };
+
+        let func = Shared::new(
+            move |ctx: Option<crate::NativeCallContext>, args: &mut crate::func::FnCallArgs| {
+                func(ctx.unwrap(), args)
+            },
+        );
+        let is_pure = f.is_pure();
+        let is_volatile = f.is_volatile();
+
+        *f = if f.is_method() {
+            RhaiFunc::Method {
+                func,
+                has_context: true,
+                is_pure,
+                is_volatile,
+            }
+        } else {
+            RhaiFunc::Pure {
+                func,
+                has_context: true,
+                is_pure,
+                is_volatile,
+            }
+        };
+
+        self.all_functions = None;
+        self.flags
+            .remove(ModuleFlags::INDEXED | ModuleFlags::INDEXED_GLOBAL_FUNCTIONS);
+
+        true
     }
 
     /// _(metadata)_ Update the metadata (parameter names/types, return type and doc-comments) of a registered function.
@@ -1528,6 +2417,299 @@ impl Module {
         options.set_into_module_raw(self, arg_types, func)
     }
 
+    /// Set a raw native Rust function into the [`Module`], returning a [`u64`] hash key.
+    ///
+    /// If there is a similar existing Rust function, it is replaced.
+    ///
+    /// # WARNING - Low Level API
+    ///
+    /// This function is very low level.  It takes a list of [`TypeId`][std::any::TypeId]'s
+    /// indicating the actual types of the parameters.
+    ///
+    /// # Arguments
+    ///
+    /// Arguments are simply passed in as a mutable array of [`&mut Dynamic`][crate::Dynamic].
+    /// The arguments are guaranteed to be of the correct types matching the [`TypeId`][std::any::TypeId]'s.
+    ///
+    /// The name the function is invoked as is available via
+    /// [`NativeCallContext::fn_name`][crate::NativeCallContext::fn_name], so the same function can
+    /// back many aliases that behave differently depending on the name.
+    ///
+    /// # Assumptions
+    ///
+    /// * **Accessibility**: The function namespace is [`FnNamespace::Internal`].
+    ///
+    /// * **Purity**: The function is assumed to be _pure_ unless it is a property setter or an index setter.
+    ///
+    /// * **Volatility**: The function is assumed to be _volatile_ -- i.e. it does not guarantee the same result for the same input(s).
+    ///
+    /// * **Metadata**: No metadata for the function is registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::any::TypeId;
+    /// use rhai::{Dynamic, Module, NativeCallContext};
+    ///
+    /// let mut module = Module::new();
+    ///
+    /// let func = |ctx: NativeCallContext, args: &mut [&mut Dynamic]| {
+    ///     let x = args[0].as_int().unwrap();
+    ///     Ok(if ctx.fn_name() == "double" { x * 2 } else { x * 3 })
+    /// };
+    ///
+    /// let hash = module.set_raw_fn("double", [TypeId::of::<i64>()], func);
+    /// assert!(module.contains_fn(hash));
+    /// let hash = module.set_raw_fn("triple", [TypeId::of::<i64>()], func);
+    /// assert!(module.contains_fn(hash));
+    /// ```
+    #[inline]
+    pub fn set_raw_fn<T: Variant + Clone>(
+        &mut self,
+        name: impl AsRef<str> + Into<Identifier>,
+        arg_types: impl AsRef<[TypeId]>,
+        func: impl Fn(crate::NativeCallContext, &mut crate::func::FnCallArgs) -> RhaiResultOf<T>
+            + SendSync
+            + 'static,
+    ) -> u64 {
+        let name = name.into();
+        let arg_types = arg_types.as_ref();
+        let is_pure = true;
+
+        #[cfg(any(not(feature = "no_index"), not(feature = "no_object")))]
+        let is_pure = is_pure && (arg_types.len() != 3 || name != crate::engine::FN_IDX_SET);
+        #[cfg(not(feature = "no_object"))]
+        let is_pure = is_pure && (arg_types.len() != 2 || !name.starts_with(crate::engine::FN_SET));
+
+        let hash = FuncRegistration::new(name)
+            .in_internal_namespace()
+            .set_into_module_raw(
+                self,
+                arg_types,
+                RhaiFunc::Method {
+                    func: Shared::new(
+                        move |ctx: Option<crate::NativeCallContext>,
+                              args: &mut crate::func::FnCallArgs| {
+                            func(ctx.unwrap(), args).map(Dynamic::from)
+                        },
+                    ),
+                    has_context: true,
+                    is_pure,
+                    is_volatile: true,
+                },
+            )
+            .hash;
+
+        if let Some((_, f)) = self.functions.as_mut().and_then(|m| m.get_mut(&hash)) {
+            f.return_type_id = Some(Module::map_type(true, TypeId::of::<T>()));
+        }
+
+        hash
+    }
+
+    /// Set a raw native Rust function into the [`Module`] that must return a value of a particular
+    /// type, returning a [`u64`] hash key.
+    ///
+    /// This is the same as [`set_raw_fn`][Module::set_raw_fn] except that the function returns a
+    /// [`Dynamic`] which is checked to be of type `R` before being handed back to the script.
+    /// A value of any other type raises [`ErrorMismatchOutputType`][crate::EvalAltResult::ErrorMismatchOutputType],
+    /// catching bugs in the function.
+    ///
+    /// The return type is also recorded into the function's metadata.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::any::TypeId;
+    /// use rhai::{Dynamic, Engine, ImmutableString, Module, NativeCallContext};
+    ///
+    /// let mut module = Module::new();
+    ///
+    /// module.set_raw_fn_with_return_type::<ImmutableString>(
+    ///     "name",
+    ///     [TypeId::of::<bool>()],
+    ///     |_: NativeCallContext, args: &mut [&mut Dynamic]| {
+    ///         Ok(if args[0].as_bool().unwrap() { "Bob".into() } else { Dynamic::UNIT })
+    ///     },
+    /// );
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.register_static_module("m", module.into());
+    ///
+    /// assert_eq!(engine.eval::<String>("m::name(true)").unwrap(), "Bob");
+    /// assert!(engine.eval::<String>("m::name(false)").is_err());
+    /// ```
+    #[inline]
+    pub fn set_raw_fn_with_return_type<R: Variant + Clone>(
+        &mut self,
+        name: impl AsRef<str> + Into<Identifier>,
+        arg_types: impl AsRef<[TypeId]>,
+        func: impl Fn(crate::NativeCallContext, &mut crate::func::FnCallArgs) -> crate::RhaiResult
+            + SendSync
+            + 'static,
+    ) -> u64 {
+        use crate::api::formatting::map_std_type_name;
+
+        let hash = self.set_raw_fn(name, arg_types, move |ctx, args| {
+            let pos = ctx.position();
+            let value = func(ctx, args)?;
+
+            if value.is::<R>() {
+                Ok(value)
+            } else {
+                Err(crate::ERR::ErrorMismatchOutputType(
+                    map_std_type_name(type_name::<R>(), true).into(),
+                    map_std_type_name(value.type_name(), true).into(),
+                    pos,
+                )
+                .into())
+            }
+        });
+
+        if let Some((_, f)) = self.functions.as_mut().and_then(|m| m.get_mut(&hash)) {
+            f.return_type_id = Some(Module::map_type(true, TypeId::of::<R>()));
+            #[cfg(feature = "metadata")]
+            {
+                f.return_type = type_name::<R>().into();
+            }
+        }
+
+        hash
+    }
+
+    /// _(internals)_ Set a prepared function into the [`Module`] under a particular [`u64`] hash
+    /// key, instead of the hash key calculated from its name and parameter types.
+    /// Exported under the `internals` feature only.
+    ///
+    /// If there is an existing function with the same hash key, it is replaced.
+    ///
+    /// This allows code generators to install functions under hash keys that they control (e.g.
+    /// read from a manifest), without recalculating them.
+    ///
+    /// # WARNING - Low Level API
+    ///
+    /// This function is very low level.  The caller is responsible for the correctness of the
+    /// hash key, including avoiding collisions with other functions in the [`Module`].
+    ///
+    /// Function calls with unqualified names look up the hash key calculated from the function
+    /// name and argument types, so the function is only found by them if the hash key matches
+    /// (e.g. it is the hash key returned by [`set_native_fn`][Module::set_native_fn] for the same
+    /// function name and parameter types).  Namespace-qualified calls (e.g. `m::func(x)`) always
+    /// use hash keys recalculated from `name` and `arg_types` when the [`Module`] is indexed.
+    ///
+    /// # Assumptions
+    ///
+    /// * **Accessibility**: The function namespace is [`FnNamespace::Internal`].
+    ///
+    /// * **Metadata**: No metadata for the function is registered.
+    #[cfg(feature = "internals")]
+    #[inline]
+    pub fn set_fn_with_hash(
+        &mut self,
+        hash: u64,
+        name: impl Into<Identifier>,
+        arg_types: impl AsRef<[TypeId]>,
+        func: RhaiFunc,
+    ) -> u64 {
+        FuncRegistration::new(name)
+            .in_internal_namespace()
+            .set_into_module_with_hash(self, Some(hash), arg_types, func)
+            .hash
+    }
+
+    /// Set a native Rust function taking any number of arguments of any types into the
+    /// [`Module`], returning a [`u64`] hash key.
+    ///
+    /// If there is an existing variadic function of the same name, it is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// Arguments are simply passed in as a mutable array of [`&mut Dynamic`][crate::Dynamic].
+    /// The function is responsible for checking the number and types of the arguments.
+    ///
+    /// # Function Resolution
+    ///
+    /// A variadic function is only called when no other function matches the call -- a
+    /// function of the same name registered with a fixed number of parameters always takes
+    /// precedence when the arguments match it.
+    ///
+    /// Namespace-qualified calls (e.g. `m::max(1, 2, 3)`) find variadic functions in the
+    /// [`Module`] and its sub-modules.  Calls without a namespace only find variadic functions in
+    /// [modules][Module] registered via [`Engine::register_global_module`].
+    ///
+    /// The returned hash is only recognized by [`contains_fn`][Module::contains_fn].
+    ///
+    /// # Assumptions
+    ///
+    /// * **Purity**: The function is assumed to be _pure_ -- all arguments are passed by value.
+    ///
+    /// * **Volatility**: The function is assumed to be _volatile_ -- i.e. it does not guarantee the same result for the same input(s).
+    ///
+    /// * **Metadata**: No metadata for the function is registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, EvalAltResult, Module, Position};
+    ///
+    /// let mut module = Module::new();
+    ///
+    /// let hash = module.set_fn_var_args("max", |_, args| {
+    ///     let mut max = None;
+    ///     for arg in args.iter() {
+    ///         let x = arg.as_int().map_err(|typ| {
+    ///             EvalAltResult::ErrorMismatchDataType("i64".into(), typ.into(), Position::NONE)
+    ///         })?;
+    ///         max = max.max(Some(x));
+    ///     }
+    ///     Ok(max.unwrap_or(0))
+    /// });
+    /// assert!(module.contains_fn(hash));
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.register_static_module("m", module.into());
+    ///
+    /// assert_eq!(engine.eval::<i64>("m::max(1, 42, 3)")?, 42);
+    /// assert_eq!(engine.eval::<i64>("m::max(1, 2)")?, 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_fn_var_args<T: Variant + Clone>(
+        &mut self,
+        name: impl Into<Identifier>,
+        func: impl Fn(crate::NativeCallContext, &mut crate::func::FnCallArgs) -> RhaiResultOf<T>
+            + SendSync
+            + 'static,
+    ) -> u64 {
+        let name = name.into();
+        let hash = calc_var_args_fn_hash(&name);
+
+        let func = RhaiFunc::Pure {
+            func: Shared::new(
+                move |ctx: Option<crate::NativeCallContext>, args: &mut crate::func::FnCallArgs| {
+                    func(ctx.unwrap(), args).map(Dynamic::from)
+                },
+            ),
+            has_context: true,
+            is_pure: true,
+            is_volatile: true,
+        };
+
+        // Variadic functions are looked up by name, so the index is not affected.
+        self.var_args_functions.insert(name, func);
+
+        hash
+    }
+
+    /// Get a native Rust function taking any number of arguments by name.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn get_var_args_fn(&self, name: &str) -> Option<&RhaiFunc> {
+        self.var_args_functions.get(name)
+    }
+
     /// Set a native Rust function into the [`Module`], returning a [`u64`] hash key.
     ///
     /// If there is a similar existing Rust function, it is replaced.
@@ -1564,25 +2746,415 @@ impl Module {
     /// ```
     /// # use rhai::Module;
     /// let mut module = Module::new();
-    /// let hash = module.set_native_fn("calc", |x: i64| Ok(42 + x));
-    /// assert!(module.contains_fn(hash));
+    /// let hash = module.set_native_fn("calc", |x: i64| Ok(42 + x));
+    /// assert!(module.contains_fn(hash));
+    /// ```
+    #[inline]
+    pub fn set_native_fn<A: 'static, const N: usize, const X: bool, R, FUNC>(
+        &mut self,
+        name: impl Into<Identifier>,
+        func: FUNC,
+    ) -> u64
+    where
+        R: Variant + Clone,
+        FUNC: RhaiNativeFunc<A, N, X, R, true> + SendSync + 'static,
+    {
+        FuncRegistration::new(name)
+            .in_internal_namespace()
+            .with_purity(true)
+            .with_volatility(false)
+            .set_into_module(self, func)
+            .hash
+    }
+
+    /// Set a native Rust function into the [`Module`] only if a condition holds, returning the
+    /// [`u64`] hash key if the function is set.
+    ///
+    /// This is the same as [`set_native_fn`][Module::set_native_fn] except that nothing is done
+    /// (and the function is not even boxed) when the condition is `false`.
+    ///
+    /// Use it together with the feature queries on [`Engine`], such as
+    /// [`Engine::supports_float`], to build the same [`Module`] for all feature sets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::{Engine, Module};
+    /// let mut module = Module::new();
+    ///
+    /// let hash = module.set_fn_if(Engine::supports_float(), "half", |x: f64| Ok(x / 2.0));
+    /// assert_eq!(hash.is_some(), Engine::supports_float());
+    ///
+    /// let hash = module.set_fn_if(false, "calc", |x: i64| Ok(42 + x));
+    /// assert!(hash.is_none());
+    /// ```
+    #[inline]
+    pub fn set_fn_if<A: 'static, const N: usize, const X: bool, R, FUNC>(
+        &mut self,
+        condition: bool,
+        name: impl Into<Identifier>,
+        func: FUNC,
+    ) -> Option<u64>
+    where
+        R: Variant + Clone,
+        FUNC: RhaiNativeFunc<A, N, X, R, true> + SendSync + 'static,
+    {
+        if condition {
+            Some(self.set_native_fn(name, func))
+        } else {
+            None
+        }
+    }
+
+    /// Set a native Rust function implementing a binary custom operator into the [`Module`],
+    /// together with the precedence of the operator, returning a [`u64`] hash key.
+    /// This function is automatically exposed to the global namespace.
+    ///
+    /// Not available under `no_custom_syntax`.
+    ///
+    /// Custom operators are parsed when the script is compiled, before any `import` statement is
+    /// run.  Therefore, the operator only becomes available to scripts when the [`Module`] is
+    /// registered into an [`Engine`] via [`register_global_module`][Engine::register_global_module]
+    /// or [`register_static_module`][Engine::register_static_module], which also registers the
+    /// operator as if by [`register_custom_operator`][Engine::register_custom_operator].
+    /// Operators of a [`Module`] that is only loaded by an `import` statement have no effect.
+    ///
+    /// An operator that cannot be registered (e.g. because it is a reserved symbol) is silently
+    /// ignored, and an operator that is already registered takes the precedence of the latest
+    /// registration.
+    ///
+    /// If there is a similar existing operator function, it is replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precedence is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Module};
+    ///
+    /// let mut module = Module::new();
+    ///
+    /// // A '#' operator with a precedence between +|- and *|/
+    /// module.set_op_fn_with_precedence("#", 160, |x: i64, y: i64| Ok((x * y) - (x + y)));
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.register_global_module(module.into());
+    ///
+    /// assert_eq!(engine.eval_expression::<i64>("1 + 2 * 3 # 4 - 5 / 6")?, 15);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "no_custom_syntax"))]
+    #[inline]
+    pub fn set_op_fn_with_precedence<A: 'static, const X: bool, R, FUNC>(
+        &mut self,
+        op: impl Into<Identifier>,
+        precedence: u8,
+        func: FUNC,
+    ) -> u64
+    where
+        R: Variant + Clone,
+        FUNC: RhaiNativeFunc<A, 2, X, R, true> + SendSync + 'static,
+    {
+        let op = op.into();
+        let precedence = crate::engine::Precedence::new(precedence)
+            .unwrap_or_else(|| panic!("precedence of operator '{op}' cannot be zero"));
+
+        self.custom_operators.insert(op.clone(), precedence);
+
+        FuncRegistration::new(op)
+            .in_global_namespace()
+            .with_purity(true)
+            .with_volatility(false)
+            .set_into_module(self, func)
+            .hash
+    }
+    /// Get an iterator to the custom operators defined by the [`Module`] and its sub-modules,
+    /// together with their precedences.
+    #[cfg(not(feature = "no_custom_syntax"))]
+    pub(crate) fn iter_custom_operators(
+        &self,
+    ) -> Box<dyn Iterator<Item = (&str, crate::engine::Precedence)> + '_> {
+        Box::new(
+            self.custom_operators
+                .iter()
+                .map(|(op, &precedence)| (op.as_str(), precedence))
+                .chain(
+                    self.modules
+                        .values()
+                        .flat_map(|m| m.iter_custom_operators()),
+                ),
+        )
+    }
+
+    /// Set a fallible Rust conversion function from type `A` to type `B` into the [`Module`],
+    /// returning a [`u64`] hash key.
+    ///
+    /// The conversion is called from scripts via the `convert` function, with the name of the
+    /// target type as the second argument: `convert(value, "TypeName")`.
+    ///
+    /// The target type name can be the full Rust type name of `B`, its last path segment
+    /// (e.g. `Celsius` for `my_crate::Celsius`), or the friendly name of `B` as registered
+    /// with the [`Engine`].
+    ///
+    /// Multiple conversions from the same type `A` to different target types can be registered.
+    /// If there is an existing conversion from `A` to `B`, it is replaced.
+    ///
+    /// # Errors
+    ///
+    /// If no conversion from `A` to the target type is registered, the `convert` call returns a
+    /// [`ErrorMismatchDataType`][crate::EvalAltResult::ErrorMismatchDataType] error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Module};
+    ///
+    /// #[derive(Debug, Clone)]
+    /// struct Celsius(f64);
+    ///
+    /// #[derive(Debug, Clone)]
+    /// struct Fahrenheit(f64);
+    ///
+    /// let mut module = Module::new();
+    /// module.set_native_fn("celsius", |x: f64| Ok(Celsius(x)));
+    /// module.set_native_fn("degrees", |x: Fahrenheit| Ok(x.0));
+    /// module.set_convert_fn(|x: Celsius| Ok(Fahrenheit(x.0 * 9.0 / 5.0 + 32.0)));
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.register_global_module(module.into());
+    ///
+    /// let result = engine.eval::<f64>(r#"degrees(convert(celsius(100.0), "Fahrenheit"))"#)?;
+    ///
+    /// assert_eq!(result, 212.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_convert_fn<A, B>(
+        &mut self,
+        func: impl Fn(A) -> RhaiResultOf<B> + SendSync + 'static,
+    ) -> u64
+    where
+        A: Variant + Clone,
+        B: Variant + Clone,
+    {
+        let arg_types = [TypeId::of::<A>(), TypeId::of::<ImmutableString>()];
+
+        // Chain to any conversions from the same type that are already registered
+        let existing = self
+            .find_fn_metadata(|name, num_params| {
+                name == crate::engine::FN_CONVERT && num_params == 2
+            })
+            .find(|f| f.param_types.as_slice() == arg_types)
+            .and_then(|f| self.get_fn(f.hash))
+            .cloned();
+
+        let f = Shared::new(
+            move |ctx: Option<crate::NativeCallContext>, args: &mut crate::func::FnCallArgs| {
+                let ctx = ctx.unwrap();
+                let target = args[1].read_lock::<ImmutableString>().unwrap().clone();
+                let full_name = type_name::<B>();
+                let short_name = full_name.rsplit("::").next().unwrap_or(full_name);
+
+                if target.as_str() == full_name
+                    || target.as_str() == short_name
+                    || target.as_str() == ctx.engine().map_type_name(full_name)
+                {
+                    let value = args[0].take().cast::<A>();
+                    return func(value).map(Dynamic::from);
+                }
+
+                match existing {
+                    Some(RhaiFunc::Pure { ref func, .. }) => func(Some(ctx), args),
+                    _ => Err(crate::ERR::ErrorMismatchDataType(
+                        target.to_string(),
+                        ctx.engine().map_type_name(args[0].type_name()).into(),
+                        ctx.position(),
+                    )
+                    .into()),
+                }
+            },
+        );
+
+        FuncRegistration::new(crate::engine::FN_CONVERT)
+            .in_global_namespace()
+            .set_into_module_raw(
+                self,
+                arg_types,
+                RhaiFunc::Pure {
+                    func: f,
+                    has_context: true,
+                    is_pure: true,
+                    is_volatile: false,
+                },
+            )
+            .hash
+    }
+
+    /// Set a native Rust function taking two mutable parameters into the [`Module`],
+    /// returning a [`u64`] hash key.
+    ///
+    /// If there is a similar existing Rust function, it is replaced.
+    ///
+    /// Both arguments are type-checked before the function is called. A mismatched argument
+    /// results in an [`ErrorMismatchDataType`][crate::EvalAltResult::ErrorMismatchDataType] error
+    /// instead of a panic.  Passing the same _shared_ value as both arguments results in an
+    /// [`ErrorDataRace`][crate::EvalAltResult::ErrorDataRace] error.
+    ///
+    /// # Write-Back of the Second Argument
+    ///
+    /// When called from a script, only the first argument is bound to the caller's variable
+    /// (as with any method-call style function).  Changes made to the second argument are only
+    /// visible to the caller if it refers to a _shared_ value, or when the function is invoked
+    /// directly with all arguments passed by reference (e.g. via
+    /// [`NativeCallContext::call_native_fn_raw`][crate::NativeCallContext::call_native_fn_raw]).
+    ///
+    /// # Assumptions
+    ///
+    /// * **Accessibility**: The function namespace is [`FnNamespace::Internal`].
+    ///
+    /// * **Purity**: The function is assumed to be _non-pure_ (so it cannot be called on constants).
+    ///
+    /// * **Volatility**: The function is assumed to be _non-volatile_ -- i.e. it guarantees the same result for the same input(s).
+    ///
+    /// * **Metadata**: No metadata for the function is registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module = Module::new();
+    /// let hash = module.set_native_fn_mut_both("swap", |x: &mut i64, y: &mut i64| {
+    ///     std::mem::swap(x, y);
+    ///     Ok(())
+    /// });
+    /// assert!(module.contains_fn(hash));
+    /// ```
+    pub fn set_native_fn_mut_both<A, B, R>(
+        &mut self,
+        name: impl Into<Identifier>,
+        func: impl Fn(&mut A, &mut B) -> RhaiResultOf<R> + SendSync + 'static,
+    ) -> u64
+    where
+        A: Variant + Clone,
+        B: Variant + Clone,
+        R: Variant + Clone,
+    {
+        let f = Shared::new(
+            move |ctx: Option<crate::NativeCallContext>, args: &mut crate::func::FnCallArgs| {
+                let ctx = ctx.unwrap();
+
+                // Borrow both arguments mutably at the same time
+                let (first, rest) = args.split_at_mut(1);
+                let (a, b) = (&mut *first[0], &mut *rest[0]);
+
+                // The same shared value cannot be locked for write twice
+                #[cfg(not(feature = "no_closure"))]
+                if let (
+                    crate::types::dynamic::Union::Shared(ref x, ..),
+                    crate::types::dynamic::Union::Shared(ref y, ..),
+                ) = (&a.0, &b.0)
+                {
+                    if Shared::ptr_eq(x, y) {
+                        return Err(crate::ERR::ErrorDataRace(
+                            format!("argument #2 of function '{}'", ctx.fn_name()),
+                            ctx.position(),
+                        )
+                        .into());
+                    }
+                }
+
+                let mismatch = |expected: &str, actual: &str| -> Box<crate::ERR> {
+                    crate::ERR::ErrorMismatchDataType(
+                        ctx.engine().map_type_name(expected).into(),
+                        ctx.engine().map_type_name(actual).into(),
+                        ctx.position(),
+                    )
+                    .into()
+                };
+
+                let (a_type, b_type) = (a.type_name(), b.type_name());
+                let mut a = a
+                    .write_lock::<A>()
+                    .ok_or_else(|| mismatch(type_name::<A>(), a_type))?;
+                let mut b = b
+                    .write_lock::<B>()
+                    .ok_or_else(|| mismatch(type_name::<B>(), b_type))?;
+
+                func(&mut a, &mut b).map(Dynamic::from)
+            },
+        );
+
+        FuncRegistration::new(name)
+            .in_internal_namespace()
+            .set_into_module_raw(
+                self,
+                [TypeId::of::<A>(), TypeId::of::<B>()],
+                RhaiFunc::Method {
+                    func: f,
+                    has_context: true,
+                    is_pure: false,
+                    is_volatile: false,
+                },
+            )
+            .hash
+    }
+
+    /// Set a Rust function that formats values of a type into text for display.
+    ///
+    /// The display function is used by `print`, `to_string` and string interpolation for values of
+    /// the type, but only when no `to_string` function is registered for the type (e.g. by the
+    /// [`Engine`] or a package).  That is, it is a fallback for the engine's default formatting:
+    /// it never overrides a global formatter.  Display functions for standard types (e.g. `INT`
+    /// or `FLOAT`) only take effect under a raw [`Engine`] without the standard packages.
+    ///
+    /// Display functions are looked up in modules registered via
+    /// [`Engine::register_global_module`], in modules imported by `import` statements, and in
+    /// static modules registered via [`Engine::register_static_module`] (but not in their
+    /// sub-modules).
+    ///
+    /// If there is an existing display function for the same type, it is replaced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Module};
+    ///
+    /// #[derive(Clone)]
+    /// struct Celsius(f64);
+    ///
+    /// let mut module = Module::new();
+    /// module.set_native_fn("celsius", |x: i64| Ok(Celsius(x as f64)));
+    /// module.set_display_fn(|x: &Celsius| format!("{:.1}°C", x.0));
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.register_global_module(module.into());
+    ///
+    /// assert_eq!(engine.eval::<String>("`${celsius(20)}`")?, "20.0°C");
+    /// # Ok(())
+    /// # }
     /// ```
     #[inline]
-    pub fn set_native_fn<A: 'static, const N: usize, const X: bool, R, FUNC>(
+    pub fn set_display_fn<T: Variant + Clone>(
         &mut self,
-        name: impl Into<Identifier>,
-        func: FUNC,
-    ) -> u64
-    where
-        R: Variant + Clone,
-        FUNC: RhaiNativeFunc<A, N, X, R, true> + SendSync + 'static,
-    {
-        FuncRegistration::new(name)
-            .in_internal_namespace()
-            .with_purity(true)
-            .with_volatility(false)
-            .set_into_module(self, func)
-            .hash
+        func: impl Fn(&T) -> String + SendSync + 'static,
+    ) -> &mut Self {
+        let func = move |value: &Dynamic| func(&value.read_lock::<T>().expect("`T`"));
+        self.display_fns
+            .insert(TypeId::of::<T>(), Shared::new(func));
+        self
+    }
+
+    /// Get the display function for a type.
+    #[inline]
+    #[must_use]
+    pub(crate) fn get_display_fn(&self, id: TypeId) -> Option<&FnDisplay> {
+        self.display_fns.get(&id).map(|f| &**f)
     }
 
     /// Set a Rust getter function taking one mutable parameter, returning a [`u64`] hash key.
@@ -1721,6 +3293,56 @@ impl Module {
         )
     }
 
+    /// Set a catch-all Rust getter for properties of a type into the [`Module`], returning a
+    /// [`u64`] hash key.
+    /// This function is automatically exposed to the global namespace.
+    ///
+    /// The getter is called with the name of the property when no specific getter for that
+    /// property is found, making it possible to support dynamic objects whose property names are
+    /// not known ahead of time.  It returns [`None`] when the property does not exist, which then
+    /// raises [`ErrorPropertyNotFound`][crate::EvalAltResult::ErrorPropertyNotFound].
+    ///
+    /// The catch-all getter is registered as an index getter taking a string, so the same function
+    /// also serves indexing with a string (e.g. `obj["field"]`).
+    /// Any existing index getter for the type taking a string is replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the type is [`Array`][crate::Array], [`Map`][crate::Map], [`String`],
+    /// [`ImmutableString`][crate::ImmutableString], `&str` or [`INT`][crate::INT].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use rhai::{Dynamic, Engine, Module};
+    ///
+    /// #[derive(Clone)]
+    /// struct Json(BTreeMap<String, i64>);
+    ///
+    /// let mut module = Module::new();
+    /// module.set_dynamic_getter_fn(|json: &mut Json, name| Ok(json.0.get(name).map(|&v| v.into())));
+    /// module.set_native_fn("make_json", || Ok(Json([("answer".to_string(), 42)].into())));
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.register_global_module(module.into());
+    ///
+    /// assert_eq!(engine.eval::<i64>("make_json().answer").unwrap(), 42);
+    /// assert!(engine.eval::<i64>("make_json().question").is_err());
+    /// ```
+    #[cfg(not(feature = "no_object"))]
+    #[inline]
+    pub fn set_dynamic_getter_fn<A: Variant + Clone>(
+        &mut self,
+        func: impl Fn(&mut A, &str) -> RhaiResultOf<Option<Dynamic>> + SendSync + 'static,
+    ) -> u64 {
+        self.set_indexer_get_fn(move |obj: &mut A, name: ImmutableString| {
+            func(obj, &name)?.ok_or_else(|| {
+                crate::ERR::ErrorPropertyNotFound(name.to_string(), crate::Position::NONE).into()
+            })
+        })
+    }
+
     /// Set a Rust index getter taking two parameters (the first one mutable) into the [`Module`],
     /// returning a [`u64`] hash key.
     /// This function is automatically exposed to the global namespace.
@@ -1930,17 +3552,27 @@ impl Module {
 
     /// Combine another [`Module`] into this [`Module`].
     /// The other [`Module`] is _consumed_ to merge into this [`Module`].
+    ///
+    /// This is the consuming counterpart of [`merge`][Module::merge]: entries are moved instead of
+    /// cloned, which is cheaper when building up a large module from owned parts.
+    ///
+    /// As with [`merge`][Module::merge], when sub-modules, variables, functions or type iterators
+    /// collide, those in the other [`Module`] win.
     #[inline]
     pub fn combine(&mut self, other: Self) -> &mut Self {
         self.modules.extend(other.modules);
         self.variables.extend(other.variables);
+        #[cfg(not(feature = "no_custom_syntax"))]
+        self.custom_operators.extend(other.custom_operators);
         match self.functions {
             Some(ref mut m) if other.functions.is_some() => m.extend(other.functions.unwrap()),
             Some(_) => (),
             None => self.functions = other.functions,
         }
+        self.var_args_functions.extend(other.var_args_functions);
         self.dynamic_functions_filter += other.dynamic_functions_filter;
         self.type_iterators.extend(other.type_iterators);
+        self.display_fns.extend(other.display_fns);
         self.all_functions = None;
         self.all_variables = None;
         self.all_type_iterators.clear();
@@ -1961,19 +3593,56 @@ impl Module {
     /// Combine another [`Module`] into this [`Module`].
     /// The other [`Module`] is _consumed_ to merge into this [`Module`].
     /// Sub-modules are flattened onto the root [`Module`], with higher level overriding lower level.
+    ///
+    /// Use this to pull the functions and variables of a sub-module up into the parent's own
+    /// namespace, so that `parent::foo()` works instead of `parent::sub::foo()`.
+    /// To keep the other [`Module`] nested, use [`set_sub_module`][Module::set_sub_module] instead.
+    ///
+    /// # Name Collisions
+    ///
+    /// Items collide when they have the same name (and, for functions, the same parameter types).
+    /// Items are combined in this order, with later ones overriding earlier ones:
+    ///
+    /// 1) items already in this [`Module`],
+    /// 2) items of the other [`Module`]'s sub-modules (recursively), in order of sub-module name,
+    /// 3) items of the other [`Module`] itself.
+    ///
+    /// Combining several modules one after another with this method means that the last one wins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::{Module, INT};
+    ///
+    /// let mut sub = Module::new();
+    /// sub.set_native_fn("foo", |x: INT| Ok(x + 1));
+    ///
+    /// let mut other = Module::new();
+    /// other.set_sub_module("sub", sub);
+    ///
+    /// let mut module = Module::new();
+    /// module.combine_flatten(other);
+    ///
+    /// assert!(module.contains_fn_by_name("foo", 1));
+    /// assert!(module.get_sub_module("sub").is_none());
+    /// ```
     #[inline]
     pub fn combine_flatten(&mut self, other: Self) -> &mut Self {
         for m in other.modules.into_values() {
             self.combine_flatten(shared_take_or_clone(m));
         }
         self.variables.extend(other.variables);
+        #[cfg(not(feature = "no_custom_syntax"))]
+        self.custom_operators.extend(other.custom_operators);
         match self.functions {
             Some(ref mut m) if other.functions.is_some() => m.extend(other.functions.unwrap()),
             Some(_) => (),
             None => self.functions = other.functions,
         }
+        self.var_args_functions.extend(other.var_args_functions);
         self.dynamic_functions_filter += other.dynamic_functions_filter;
         self.type_iterators.extend(other.type_iterators);
+        self.display_fns.extend(other.display_fns);
         self.all_functions = None;
         self.all_variables = None;
         self.all_type_iterators.clear();
@@ -2020,6 +3689,9 @@ impl Module {
         for (&k, v) in &other.type_iterators {
             self.type_iterators.entry(k).or_insert_with(|| v.clone());
         }
+        for (&k, v) in &other.display_fns {
+            self.display_fns.entry(k).or_insert_with(|| v.clone());
+        }
 
         self.all_functions = None;
         self.all_variables = None;
@@ -2039,11 +3711,217 @@ impl Module {
     }
 
     /// Merge another [`Module`] into this [`Module`].
+    ///
+    /// Variables, functions (native Rust and scripted), sub-modules and type iterators in the
+    /// other [`Module`] are copied into this [`Module`].  When they collide, those in the other
+    /// [`Module`] win.  Sub-modules are replaced as a whole, not merged.
+    ///
+    /// Use [`merge_with_strategy`][Module::merge_with_strategy] for other ways to handle
+    /// collisions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module = Module::new();
+    /// module.set_var("x", 1_i64);
+    /// module.set_var("y", 2_i64);
+    ///
+    /// let mut other = Module::new();
+    /// other.set_var("x", 42_i64);
+    ///
+    /// module.merge(&other);
+    ///
+    /// assert_eq!(module.get_var_value::<i64>("x").unwrap(), 42);
+    /// assert_eq!(module.get_var_value::<i64>("y").unwrap(), 2);
+    /// ```
     #[inline(always)]
     pub fn merge(&mut self, other: &Self) -> &mut Self {
         self.merge_filtered(other, |_, _, _, _, _| true)
     }
 
+    /// Merge another [`Module`] into this [`Module`], returning the signatures (name and number of
+    /// parameters) of script-defined functions that are defined in both.
+    ///
+    /// Not available under `no_function`.
+    ///
+    /// As with [`merge`][Module::merge], functions in the other [`Module`] override those in this
+    /// [`Module`]; the returned list (sorted by name then number of parameters) makes it possible
+    /// to detect such overrides.  Only the top-level functions are checked, not those in
+    /// sub-modules.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Module, Scope};
+    ///
+    /// let engine = Engine::new();
+    ///
+    /// let ast1 = engine.compile("fn foo(x) { x } fn bar() { 1 }")?;
+    /// let ast2 = engine.compile("fn foo(x) { x + 1 } fn bar(x) { 2 }")?;
+    ///
+    /// let mut module = Module::eval_ast_as_new(Scope::new(), &ast1, &engine)?;
+    /// let other = Module::eval_ast_as_new(Scope::new(), &ast2, &engine)?;
+    ///
+    /// assert_eq!(module.merge_checked(&other), [("foo".to_string(), 1)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "no_function"))]
+    pub fn merge_checked(&mut self, other: &Self) -> Vec<(String, usize)> {
+        let mut conflicts: Vec<_> = self
+            .iter_conflicting_fns(other, true)
+            .map(|m| (m.name.to_string(), m.num_params))
+            .collect();
+        conflicts.sort();
+
+        self.merge(other);
+
+        conflicts
+    }
+    /// Get an iterator to the metadata of functions in another [`Module`] that are also defined in
+    /// this [`Module`].
+    ///
+    /// If `script_only` is `true`, only functions that are script-defined in both are included.
+    fn iter_conflicting_fns<'a>(
+        &'a self,
+        other: &'a Self,
+        script_only: bool,
+    ) -> impl Iterator<Item = &'a FuncMetadata> + 'a {
+        other
+            .iter_fn()
+            .filter(move |(f, m)| {
+                self.get_fn(m.hash).map_or(false, |existing| {
+                    !script_only || (f.is_script() && existing.is_script())
+                })
+            })
+            .map(|(_, m)| m)
+    }
+
+    /// Merge another [`Module`] into this [`Module`], using a [`MergeStrategy`] to handle
+    /// variables, functions and sub-modules that are defined in both.
+    ///
+    /// Under [`MergeStrategy::Error`], nothing is merged if any entry is defined in both
+    /// [modules][Module]; instead, the names of the entries are returned (sorted), with functions
+    /// named as `name/number of parameters`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::{Dynamic, Module, MergeStrategy};
+    ///
+    /// let mut module = Module::new();
+    /// module.set_var("x", 1_i64);
+    ///
+    /// let mut other = Module::new();
+    /// other.set_var("x", 41_i64);
+    /// other.set_var("y", 2_i64);
+    ///
+    /// assert_eq!(module.clone().merge_with_strategy(&other, &MergeStrategy::Error).unwrap_err(), ["x"]);
+    ///
+    /// let mut kept = module.clone();
+    /// kept.merge_with_strategy(&other, &MergeStrategy::KeepExisting).unwrap();
+    /// assert_eq!(kept.get_var_value::<i64>("x").unwrap(), 1);
+    /// assert_eq!(kept.get_var_value::<i64>("y").unwrap(), 2);
+    ///
+    /// let sum = MergeStrategy::custom(|_, old, new| {
+    ///     Dynamic::from(old.as_int().unwrap() + new.as_int().unwrap())
+    /// });
+    /// module.merge_with_strategy(&other, &sum).unwrap();
+    /// assert_eq!(module.get_var_value::<i64>("x").unwrap(), 42);
+    /// ```
+    pub fn merge_with_strategy(
+        &mut self,
+        other: &Self,
+        strategy: &MergeStrategy,
+    ) -> Result<&mut Self, Vec<String>> {
+        match strategy {
+            MergeStrategy::Overwrite => Ok(self.merge(other)),
+            MergeStrategy::Error => {
+                let mut conflicts: Vec<_> = other
+                    .variables
+                    .keys()
+                    .filter(|&name| self.variables.contains_key(name))
+                    .map(ToString::to_string)
+                    .chain(
+                        self.iter_conflicting_fns(other, false)
+                            .map(|m| format!("{}/{}", m.name, m.num_params)),
+                    )
+                    .chain(
+                        other
+                            .modules
+                            .keys()
+                            .filter(|&name| self.modules.contains_key(name))
+                            .map(ToString::to_string),
+                    )
+                    .collect();
+
+                if conflicts.is_empty() {
+                    Ok(self.merge(other))
+                } else {
+                    conflicts.sort();
+                    Err(conflicts)
+                }
+            }
+            MergeStrategy::KeepExisting => {
+                let mut other = other.clone();
+                other
+                    .variables
+                    .retain(|name, _| !self.variables.contains_key(name));
+                if let Some(ref mut functions) = other.functions {
+                    functions.retain(|&hash, _| self.get_fn(hash).is_none());
+                }
+                other
+                    .modules
+                    .retain(|name, _| !self.modules.contains_key(name));
+                Ok(self.merge(&other))
+            }
+            MergeStrategy::Custom(resolve) => {
+                let mut other = other.clone();
+                for (name, value) in &mut other.variables {
+                    if let Some(existing) = self.variables.get(name) {
+                        *value = resolve(name, existing, value);
+                    }
+                }
+                for (name, module) in &mut other.modules {
+                    if let Some(existing) = self.modules.get(name) {
+                        let mut m = (**existing).clone();
+                        m.merge_with_strategy(module, strategy)?;
+                        *module = m.into();
+                    }
+                }
+                Ok(self.merge(&other))
+            }
+        }
+    }
+
+    /// Create a new [`Module`] holding clones of only those functions in this [`Module`] whose
+    /// names satisfy a filter predicate.
+    ///
+    /// Sub-modules, variables, type iterators and custom operators are not included.
+    #[must_use]
+    pub(crate) fn clone_fns_filtered(&self, filter: impl Fn(&str) -> bool) -> Self {
+        let mut module = Self::new();
+
+        module.functions = self.functions.as_ref().map(|functions| {
+            functions
+                .iter()
+                .filter(|(.., (_, m))| filter(&m.name))
+                .map(|(&k, f)| (k, f.clone()))
+                .collect()
+        });
+        module.var_args_functions = self
+            .var_args_functions
+            .iter()
+            .filter(|(name, ..)| filter(name))
+            .map(|(k, f)| (k.clone(), f.clone()))
+            .collect();
+        module.dynamic_functions_filter += &self.dynamic_functions_filter;
+
+        module
+    }
+
     /// Merge another [`Module`] into this [`Module`] based on a filter predicate.
     pub(crate) fn merge_filtered(
         &mut self,
@@ -2059,6 +3937,9 @@ impl Module {
         self.modules.extend(other.modules.clone());
 
         self.variables.extend(other.variables.clone());
+        #[cfg(not(feature = "no_custom_syntax"))]
+        self.custom_operators
+            .extend(other.custom_operators.iter().map(|(k, &v)| (k.clone(), v)));
 
         if let Some(ref functions) = other.functions {
             match self.functions {
@@ -2073,63 +3954,244 @@ impl Module {
                 None => self.functions.clone_from(&other.functions),
             }
         }
+        self.var_args_functions
+            .extend(other.var_args_functions.clone());
         self.dynamic_functions_filter += &other.dynamic_functions_filter;
 
         self.type_iterators.extend(other.type_iterators.clone());
+        self.display_fns.extend(other.display_fns.clone());
+        self.all_functions = None;
+        self.all_variables = None;
+        self.all_type_iterators.clear();
+        self.flags
+            .remove(ModuleFlags::INDEXED | ModuleFlags::INDEXED_GLOBAL_FUNCTIONS);
+
+        #[cfg(feature = "metadata")]
+        {
+            if !self.doc.is_empty() {
+                self.doc.push_str("\n");
+            }
+            self.doc.push_str(&other.doc);
+        }
+
+        self
+    }
+
+    /// Retain only the variables and functions whose names satisfy a predicate, removing all
+    /// sub-modules.
+    #[cfg(not(feature = "no_module"))]
+    pub(crate) fn retain_by_name(&mut self, filter: impl Fn(&str) -> bool) -> &mut Self {
+        self.variables.retain(|name, _| filter(name));
+        self.functions = std::mem::take(&mut self.functions).map(|m| {
+            m.into_iter()
+                .filter(|(.., (_, m))| filter(&m.name))
+                .collect()
+        });
+        self.modules.clear();
+
+        // Retained native functions may still be dynamic, so keep the filter as is
+        self.all_functions = None;
+        self.all_variables = None;
+        self.all_type_iterators.clear();
+        self.flags
+            .remove(ModuleFlags::INDEXED | ModuleFlags::INDEXED_GLOBAL_FUNCTIONS);
+        self
+    }
+
+    /// Filter out the functions, retaining only some script-defined functions based on a filter predicate.
+    #[cfg(not(feature = "no_function"))]
+    #[inline]
+    pub(crate) fn retain_script_functions(
+        &mut self,
+        filter: impl Fn(FnNamespace, FnAccess, &str, usize) -> bool,
+    ) -> &mut Self {
+        self.functions = std::mem::take(&mut self.functions).map(|m| {
+            m.into_iter()
+                .filter(|(.., (f, m))| {
+                    if f.is_script() {
+                        filter(m.namespace, m.access, &m.name, m.num_params)
+                    } else {
+                        false
+                    }
+                })
+                .collect()
+        });
+
+        self.dynamic_functions_filter.clear();
         self.all_functions = None;
         self.all_variables = None;
         self.all_type_iterators.clear();
         self.flags
             .remove(ModuleFlags::INDEXED | ModuleFlags::INDEXED_GLOBAL_FUNCTIONS);
+        self
+    }
+
+    /// Get the number of variables, functions and type iterators in the [`Module`].
+    #[inline(always)]
+    #[must_use]
+    pub fn count(&self) -> (usize, usize, usize) {
+        (
+            self.variables.len(),
+            self.functions.as_ref().map_or(0, StraightHashMap::len),
+            self.type_iterators.len(),
+        )
+    }
+
+    /// Does this [`Module`] expose the same API as another [`Module`]?
+    ///
+    /// Two modules expose the same API if they contain the same set of public function names
+    /// and numbers of parameters, the same variable names, and sub-modules (of the same names)
+    /// that in turn expose the same API.
+    ///
+    /// Function bodies, parameter types and variable values are not compared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module1 = Module::new();
+    /// module1.set_var("answer", 42_i64);
+    /// module1.set_native_fn("calc", |x: i64| Ok(42 + x));
+    ///
+    /// let mut module2 = Module::new();
+    /// module2.set_var("answer", 0_i64);
+    /// module2.set_native_fn("calc", |x: i64| Ok(x * 2));
+    ///
+    /// assert!(module1.same_api(&module2));
+    ///
+    /// module2.set_native_fn("calc", |x: i64, y: i64| Ok(x + y));
+    ///
+    /// assert!(!module1.same_api(&module2));
+    /// ```
+    #[must_use]
+    pub fn same_api(&self, other: &Self) -> bool {
+        fn fn_signatures(module: &Module) -> BTreeSet<(&str, usize)> {
+            module
+                .iter_fn()
+                .filter(|(_, f)| f.access.is_public())
+                .map(|(_, f)| (f.name.as_str(), f.num_params))
+                .collect()
+        }
+
+        self.variables.keys().eq(other.variables.keys())
+            && fn_signatures(self) == fn_signatures(other)
+            && self.modules.len() == other.modules.len()
+            && self
+                .modules
+                .iter()
+                .zip(other.modules.iter())
+                .all(|((k1, m1), (k2, m2))| k1 == k2 && m1.same_api(m2))
+    }
+
+    /// Generate script source text that describes the contents of this [`Module`].
+    ///
+    /// Variables whose values can be written as script literals (`()`, booleans, numbers,
+    /// characters, strings, and arrays or object maps of them) are emitted as `export const`
+    /// statements, so evaluating the script as a module reconstructs them.
+    ///
+    /// Script-defined functions are re-emitted from their compiled bodies, so the result is
+    /// equivalent to, but not necessarily identical with, the original source.
+    ///
+    /// Everything else is listed as comments only: native Rust functions, script-defined functions
+    /// that use closures, `switch` statements or custom syntax, variables whose names are not valid
+    /// identifiers or whose values cannot be written as literals, and sub-modules.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module = Module::new();
+    /// module.set_var("answer", 42_i64);
+    /// module.set_var("name", "hello");
+    /// module.set_native_fn("calc", |x: i64| Ok(42 + x));
+    ///
+    /// let script = module.to_script();
+    ///
+    /// assert!(script.contains("export const answer = 42;"));
+    /// assert!(script.contains(r#"export const name = "hello";"#));
+    /// assert!(script.contains("// native fn calc(_)"));
+    /// ```
+    #[must_use]
+    pub fn to_script(&self) -> String {
+        use std::fmt::Write;
 
+        let mut script = String::new();
+
+        if let Some(id) = self.id() {
+            let _ = writeln!(script, "// Module: {id}");
+        }
         #[cfg(feature = "metadata")]
-        {
-            if !self.doc.is_empty() {
-                self.doc.push_str("\n");
+        for line in self.doc().lines() {
+            let _ = writeln!(script, "//! {}", line.trim_start_matches("//!").trim());
+        }
+
+        for (name, value) in self.iter_var() {
+            let mut literal = String::new();
+
+            if !crate::tokenizer::is_valid_function_name(name) {
+                let _ = writeln!(
+                    script,
+                    "// export const {name:?}: {} (name is not a valid identifier)",
+                    value.type_name()
+                );
+            } else if script::write_value(&mut literal, value) {
+                let _ = writeln!(script, "export const {name} = {literal};");
+            } else {
+                let _ = writeln!(
+                    script,
+                    "// export const {name}: {} (value cannot be written as a literal)",
+                    value.type_name()
+                );
             }
-            self.doc.push_str(&other.doc);
         }
 
-        self
-    }
+        let mut functions = self
+            .iter_fn()
+            .map(|(f, m)| {
+                let private = if m.access.is_private() {
+                    "private "
+                } else {
+                    ""
+                };
 
-    /// Filter out the functions, retaining only some script-defined functions based on a filter predicate.
-    #[cfg(not(feature = "no_function"))]
-    #[inline]
-    pub(crate) fn retain_script_functions(
-        &mut self,
-        filter: impl Fn(FnNamespace, FnAccess, &str, usize) -> bool,
-    ) -> &mut Self {
-        self.functions = std::mem::take(&mut self.functions).map(|m| {
-            m.into_iter()
-                .filter(|(.., (f, m))| {
-                    if f.is_script() {
-                        filter(m.namespace, m.access, &m.name, m.num_params)
-                    } else {
-                        false
+                #[cfg(not(feature = "no_function"))]
+                if let Some(fn_def) = f.get_script_fn_def() {
+                    let mut text = String::new();
+
+                    if script::write_fn_def(&mut text, fn_def) {
+                        return (m.name.clone(), m.num_params, text);
                     }
-                })
-                .collect()
-        });
 
-        self.dynamic_functions_filter.clear();
-        self.all_functions = None;
-        self.all_variables = None;
-        self.all_type_iterators.clear();
-        self.flags
-            .remove(ModuleFlags::INDEXED | ModuleFlags::INDEXED_GLOBAL_FUNCTIONS);
-        self
-    }
+                    let params = fn_def
+                        .params
+                        .iter()
+                        .map(ImmutableString::as_str)
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let text = format!("// {private}fn {}({params}) {{ ... }}", m.name);
+                    return (m.name.clone(), m.num_params, text);
+                }
 
-    /// Get the number of variables, functions and type iterators in the [`Module`].
-    #[inline(always)]
-    #[must_use]
-    pub fn count(&self) -> (usize, usize, usize) {
-        (
-            self.variables.len(),
-            self.functions.as_ref().map_or(0, StraightHashMap::len),
-            self.type_iterators.len(),
-        )
+                #[cfg(feature = "no_function")]
+                let _ = f;
+
+                let params = vec!["_"; m.num_params].join(", ");
+                let text = format!("// native {private}fn {}({params})", m.name);
+                (m.name.clone(), m.num_params, text)
+            })
+            .collect::<Vec<_>>();
+        functions.sort();
+
+        for (.., text) in functions {
+            script.push_str(&text);
+            script.push('\n');
+        }
+
+        for (name, _) in self.iter_sub_modules() {
+            let _ = writeln!(script, "// sub-module {name}");
+        }
+
+        script
     }
 
     /// Get an iterator to the sub-modules in the [`Module`].
@@ -2144,6 +4206,97 @@ impl Module {
     ) -> impl Iterator<Item = (&Identifier, &SharedModule)> {
         self.modules.iter()
     }
+    /// Get an iterator to the names of the sub-modules in the [`Module`], in sorted order.
+    #[inline(always)]
+    pub fn sub_module_names(&self) -> impl Iterator<Item = &str> {
+        self.modules.keys().map(SmartString::as_str)
+    }
+    /// Get an iterator over all the sub-modules in the [`Module`], at any depth, together with
+    /// their paths from this [`Module`].
+    ///
+    /// Sub-modules are visited depth-first, in order of their names.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut sub_module2 = Module::new();
+    /// sub_module2.set_var("answer", 42_i64);
+    ///
+    /// let mut sub_module = Module::new();
+    /// sub_module.set_sub_module("universe", sub_module2);
+    ///
+    /// let mut module = Module::new();
+    /// module.set_sub_module("life", sub_module);
+    /// module.set_sub_module("death", Module::new());
+    ///
+    /// let paths: Vec<_> = module.descendants().map(|(path, _)| path.join("::")).collect();
+    ///
+    /// assert_eq!(paths, ["death", "life", "life::universe"]);
+    /// ```
+    #[cfg(not(feature = "no_module"))]
+    #[must_use]
+    pub fn descendants(&self) -> impl Iterator<Item = (Vec<&str>, &Self)> {
+        fn walk<'a>(
+            module: &'a Module,
+            path: &mut Vec<&'a str>,
+            list: &mut Vec<(Vec<&'a str>, &'a Module)>,
+        ) {
+            for (name, m) in &module.modules {
+                path.push(name.as_str());
+                list.push((path.clone(), &**m));
+                walk(m, path, list);
+                path.pop();
+            }
+        }
+
+        let mut list = Vec::new();
+        walk(self, &mut Vec::new(), &mut list);
+        list.into_iter()
+    }
+    /// Call a function on all the sub-modules in the [`Module`], at any depth, together with
+    /// their paths from this [`Module`], allowing them to be modified.
+    ///
+    /// Sub-modules are visited depth-first, in order of their names.  A sub-module is visited
+    /// before its own sub-modules, so changes made to it are reflected in the remaining traversal.
+    ///
+    /// Shared sub-modules are cloned before being modified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut sub_module = Module::new();
+    /// sub_module.set_sub_module("universe", Module::new());
+    ///
+    /// let mut module = Module::new();
+    /// module.set_sub_module("life", sub_module);
+    ///
+    /// module.descendants_mut(|path, m| {
+    ///     m.set_var("depth", path.len() as i64);
+    /// });
+    ///
+    /// let universe = module.get_sub_module("life").unwrap().get_sub_module("universe").unwrap();
+    /// assert_eq!(universe.get_var_value::<i64>("depth").unwrap(), 2);
+    /// ```
+    #[cfg(not(feature = "no_module"))]
+    pub fn descendants_mut(&mut self, mut func: impl FnMut(&[&str], &mut Self)) {
+        fn walk<'a>(
+            module: &'a mut Module,
+            path: &mut Vec<&'a str>,
+            func: &mut dyn FnMut(&[&str], &mut Module),
+        ) {
+            for (name, m) in module.get_sub_modules_mut().iter_mut() {
+                let m = crate::func::shared_make_mut(m);
+                path.push(name.as_str());
+                func(path.as_slice(), m);
+                walk(m, path, func);
+                path.pop();
+            }
+        }
+
+        walk(self, &mut Vec::new(), &mut func);
+    }
 
     /// Get an iterator to the variables in the [`Module`].
     #[inline(always)]
@@ -2155,6 +4308,29 @@ impl Module {
     pub(crate) fn iter_var_raw(&self) -> impl Iterator<Item = (&Identifier, &Dynamic)> {
         self.variables.iter()
     }
+    /// Get an iterator to the names of the variables in the [`Module`], in sorted order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module = Module::new();
+    /// module.set_var("y", 2_i64);
+    /// module.set_var("x", 1_i64);
+    ///
+    /// assert_eq!(module.var_count(), 2);
+    /// assert_eq!(module.var_names().collect::<Vec<_>>(), ["x", "y"]);
+    /// ```
+    #[inline(always)]
+    pub fn var_names(&self) -> impl Iterator<Item = &str> {
+        self.variables.keys().map(SmartString::as_str)
+    }
+    /// Get the number of variables in the [`Module`].
+    #[inline(always)]
+    #[must_use]
+    pub fn var_count(&self) -> usize {
+        self.variables.len()
+    }
 
     /// Get an iterator to the custom types in the [`Module`].
     #[inline(always)]
@@ -2163,6 +4339,47 @@ impl Module {
         self.custom_types.iter()
     }
 
+    /// Get the set of all types taken as parameters by, or returned from, native Rust functions
+    /// in the [`Module`].
+    ///
+    /// Parameters taking any [`Dynamic`] value, and functions returning [`Dynamic`] or `()`, are
+    /// not included.  Functions in sub-modules are not included.
+    ///
+    /// Return types are taken from the Rust return types of the functions, so a raw function
+    /// returning [`Dynamic`] only contributes its parameter types unless it is registered via
+    /// [`set_raw_fn_with_return_type`][Module::set_raw_fn_with_return_type].
+    ///
+    /// This is useful for checking that all custom types used by a [`Module`] are known to the
+    /// host before using it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::any::TypeId;
+    /// # use rhai::{Dynamic, Module};
+    /// #[derive(Clone)]
+    /// struct Point(i64, i64);
+    ///
+    /// let mut module = Module::new();
+    /// module.set_native_fn("x", |p: &mut Point| Ok(p.0));
+    /// module.set_native_fn("scale", |p: Point, k: i64, _: Dynamic| Ok(Point(p.0 * k, p.1 * k)));
+    /// module.set_native_fn("is_origin", |p: &mut Point| Ok(p.0 == 0 && p.1 == 0));
+    ///
+    /// let types = module.referenced_types();
+    ///
+    /// assert_eq!(types.len(), 3);
+    /// assert!(types.contains(&TypeId::of::<Point>()));
+    /// assert!(types.contains(&TypeId::of::<i64>()));
+    /// assert!(types.contains(&TypeId::of::<bool>()));
+    /// ```
+    #[must_use]
+    pub fn referenced_types(&self) -> BTreeSet<TypeId> {
+        self.iter_fn()
+            .flat_map(|(_, m)| m.param_types.iter().copied().chain(m.return_type_id))
+            .filter(|&type_id| type_id != TypeId::of::<Dynamic>() && type_id != TypeId::of::<()>())
+            .collect()
+    }
+
     /// Get an iterator to the functions in the [`Module`].
     #[inline]
     #[allow(dead_code)]
@@ -2173,6 +4390,190 @@ impl Module {
             .map(|(f, m)| (f, &**m))
     }
 
+    /// Get an iterator over the functions (native Rust and scripted) in the [`Module`], in no
+    /// particular order.
+    ///
+    /// See [`iter_fn_info_sorted`][Module::iter_fn_info_sorted] for a sorted version.
+    ///
+    /// Each item is a tuple of:
+    /// 1) The [`u64`] hash key of the function, as returned when it is registered.
+    /// 2) Function name (as string slice).
+    /// 3) Number of parameters.
+    ///
+    /// Functions in sub-modules and [variadic functions][Module::set_fn_var_args] are not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module = Module::new();
+    /// let hash = module.set_native_fn("calc", |x: i64, y: i64| Ok(x + y));
+    ///
+    /// assert_eq!(module.fn_count(), 1);
+    /// assert_eq!(module.iter_fn_info().collect::<Vec<_>>(), [(hash, "calc", 2)]);
+    /// ```
+    #[inline]
+    pub fn iter_fn_info(&self) -> impl Iterator<Item = (u64, &str, usize)> {
+        self.functions
+            .iter()
+            .flat_map(StraightHashMap::iter)
+            .map(|(&hash, (_, m))| (hash, m.name.as_str(), m.num_params))
+    }
+
+    /// Get an iterator over the functions (native Rust and scripted) in the [`Module`], sorted by
+    /// name and then by number of parameters.
+    ///
+    /// Each item is the same as in [`iter_fn_info`][Module::iter_fn_info].  Use this for output
+    /// that must be reproducible, such as golden-file tests of generated bindings.
+    ///
+    /// Overloads with the same name and number of parameters are ordered by hash key, which is
+    /// only stable across runs when a fixed hashing seed is set
+    /// (see [`set_hashing_seed`][crate::config::hashing::set_hashing_seed]).
+    ///
+    /// # Performance
+    ///
+    /// Functions are stored in a hash map, so each call collects and sorts them all
+    /// (`O(n log n)` with an allocation).  Prefer [`iter_fn_info`][Module::iter_fn_info] when the
+    /// order does not matter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module = Module::new();
+    /// module.set_native_fn("foo", |x: i64, y: i64| Ok(x + y));
+    /// module.set_native_fn("bar", |x: i64| Ok(x));
+    /// module.set_native_fn("foo", |x: i64| Ok(x));
+    ///
+    /// let names: Vec<_> = module.iter_fn_info_sorted().map(|(_, name, n)| (name, n)).collect();
+    ///
+    /// assert_eq!(names, [("bar", 1), ("foo", 1), ("foo", 2)]);
+    /// ```
+    #[inline]
+    pub fn iter_fn_info_sorted(&self) -> impl Iterator<Item = (u64, &str, usize)> {
+        let mut functions: Vec<_> = self.iter_fn_info().collect();
+        functions.sort_unstable_by(|(h1, n1, p1), (h2, n2, p2)| {
+            n1.cmp(n2).then(p1.cmp(p2)).then(h1.cmp(h2))
+        });
+        functions.into_iter()
+    }
+
+    /// Get the number of functions (native Rust and scripted) in the [`Module`].
+    ///
+    /// Functions in sub-modules and [variadic functions][Module::set_fn_var_args] are not included.
+    #[inline]
+    #[must_use]
+    pub fn fn_count(&self) -> usize {
+        self.functions.as_ref().map_or(0, StraightHashMap::len)
+    }
+
+    /// Calculate a fingerprint of the public API of the [`Module`].
+    ///
+    /// The fingerprint covers the names of variables, the names and number of parameters of public
+    /// functions, and (recursively) the names and APIs of sub-modules.
+    /// Variable values and function bodies are ignored, so two [modules][Module] exposing the same
+    /// API have the same fingerprint.
+    ///
+    /// The fingerprint is reproducible across processes only when a fixed hashing seed is set
+    /// (see [`config::hashing::set_hashing_seed`][crate::config::hashing::set_hashing_seed]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// let mut module = Module::new();
+    /// module.set_var("answer", 42_i64);
+    /// module.set_native_fn("calc", |x: i64| Ok(x + 1));
+    ///
+    /// let mut other = Module::new();
+    /// other.set_native_fn("calc", |x: i64| Ok(x * 2));
+    /// other.set_var("answer", 0_i64);
+    ///
+    /// assert_eq!(module.api_fingerprint(), other.api_fingerprint());
+    ///
+    /// other.set_native_fn("calc", |x: i64, y: i64| Ok(x * y));
+    ///
+    /// assert_ne!(module.api_fingerprint(), other.api_fingerprint());
+    /// ```
+    #[must_use]
+    pub fn api_fingerprint(&self) -> u64 {
+        fn write_api(module: &Module, hasher: &mut impl Hasher) {
+            hasher.write_usize(module.variables.len());
+            for name in module.variables.keys() {
+                name.as_str().hash(hasher);
+            }
+
+            let mut functions: Vec<_> = module
+                .iter_fn()
+                .filter(|(_, f)| f.access.is_public())
+                .map(|(_, f)| (f.name.as_str(), f.num_params))
+                .collect();
+            functions.sort_unstable();
+            functions.hash(hasher);
+
+            hasher.write_usize(module.modules.len());
+            for (name, m) in &module.modules {
+                name.as_str().hash(hasher);
+                write_api(m, hasher);
+            }
+        }
+
+        let hasher = &mut get_hasher();
+        write_api(self, hasher);
+        hasher.finish()
+    }
+
+    /// Walk the [`Module`], together with all its sub-modules, with a [visitor][ModuleVisitor].
+    ///
+    /// Within each [`Module`], variables are visited first, then functions, then sub-modules
+    /// (in order of their names).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::{Dynamic, Module, ModuleVisitor};
+    ///
+    /// #[derive(Default)]
+    /// struct Counter(usize);
+    ///
+    /// impl ModuleVisitor for Counter {
+    ///     fn visit_var(&mut self, _: &[&str], _: &str, _: &Dynamic) {
+    ///         self.0 += 1;
+    ///     }
+    /// }
+    ///
+    /// let mut sub_module = Module::new();
+    /// sub_module.set_var("x", 1_i64);
+    ///
+    /// let mut module = Module::new();
+    /// module.set_var("y", 2_i64);
+    /// module.set_sub_module("sub", sub_module);
+    ///
+    /// let mut counter = Counter::default();
+    /// module.accept(&mut counter);
+    ///
+    /// assert_eq!(counter.0, 2);
+    /// ```
+    pub fn accept(&self, visitor: &mut impl ModuleVisitor) {
+        fn walk<'a>(module: &'a Module, path: &mut Vec<&'a str>, visitor: &mut impl ModuleVisitor) {
+            for (name, value) in &module.variables {
+                visitor.visit_var(path.as_slice(), name.as_str(), value);
+            }
+            for (_, metadata) in module.iter_fn() {
+                visitor.visit_fn(path.as_slice(), metadata);
+            }
+            for (name, m) in &module.modules {
+                visitor.enter_sub_module(name.as_str());
+                path.push(name.as_str());
+                walk(m, path, visitor);
+                path.pop();
+                visitor.exit_sub_module();
+            }
+        }
+
+        walk(self, &mut Vec::new(), visitor);
+    }
+
     /// Get an iterator over all script-defined functions in the [`Module`].
     ///
     /// Function metadata includes:
@@ -2262,6 +4663,57 @@ impl Module {
 
         Self::eval_ast_as_new_raw(engine, &mut scope, global, ast)
     }
+    /// Create a new [`Module`] by compiling and evaluating a script with a separate, restricted
+    /// [`Engine`], keeping only the resulting variables.
+    ///
+    /// The script can only call functions available in `engine`, so a sandboxing host typically
+    /// uses an [`Engine`] built via [`Engine::new_raw`] with only an explicitly chosen set of
+    /// packages and functions registered.
+    ///
+    /// No [`Engine`] configuration is cloned: the [`Engine`] passed in is used as-is.  Building a
+    /// dedicated restricted [`Engine`] (once, and keeping it around) is the only cost.
+    ///
+    /// # Functions Are Dropped
+    ///
+    /// Script-defined functions are _not_ kept in the resulting [`Module`].  When called, they
+    /// would run against the functions of the [`Engine`] that calls them, escaping the sandbox.
+    /// For the same reason, function pointers stored in variables should not be trusted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Module};
+    ///
+    /// let mut main_engine = Engine::new();
+    /// main_engine.register_fn("secret", || 42_i64);
+    ///
+    /// let sandbox = Engine::new_raw();
+    ///
+    /// let module = Module::eval_isolated(&sandbox, "export const X = 40 + 2;")?;
+    /// assert_eq!(module.get_var_value::<i64>("X").unwrap(), 42);
+    ///
+    /// // `secret` is not available to the sandboxed script
+    /// assert!(Module::eval_isolated(&sandbox, "export const X = secret();").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "no_module"))]
+    pub fn eval_isolated(engine: &crate::Engine, script: &str) -> RhaiResultOf<Self> {
+        let ast = engine.compile(script)?;
+        let module = Self::eval_ast_as_new(crate::Scope::new(), &ast, engine)?;
+
+        let mut isolated = Self::new();
+
+        if let Some(id) = module.id() {
+            isolated.set_id(id);
+        }
+        for (name, value) in module.iter_var() {
+            isolated.set_var(name, value.clone());
+        }
+
+        Ok(isolated)
+    }
     /// Create a new [`Module`] by evaluating an [`AST`][crate::AST].
     ///
     /// The entire [`AST`][crate::AST] is encapsulated into each function, allowing functions to
@@ -2438,18 +4890,30 @@ impl Module {
             variables: &mut StraightHashMap<Dynamic>,
             functions: &mut StraightHashMap<RhaiFunc>,
             type_iterators: &mut BTreeMap<TypeId, Shared<FnIterator>>,
+            has_var_read_hooks: &mut bool,
         ) -> bool {
             let mut contains_indexed_global_functions = false;
 
             for (name, m) in &module.modules {
                 // Index all the sub-modules first.
                 path.push(name);
-                if index_module(m, path, variables, functions, type_iterators) {
+                if index_module(
+                    m,
+                    path,
+                    variables,
+                    functions,
+                    type_iterators,
+                    has_var_read_hooks,
+                ) {
                     contains_indexed_global_functions = true;
                 }
                 path.pop();
             }
 
+            if module.on_var_read.is_some() {
+                *has_var_read_hooks = true;
+            }
+
             // Index all variables
             for (var_name, value) in &module.variables {
                 let hash_var = crate::calc_var_hash(path.iter().copied(), var_name);
@@ -2547,6 +5011,7 @@ impl Module {
             let mut functions =
                 new_hash_map(self.functions.as_ref().map_or(0, StraightHashMap::len));
             let mut type_iterators = BTreeMap::new();
+            let mut has_var_read_hooks = false;
 
             path.push("");
 
@@ -2556,10 +5021,13 @@ impl Module {
                 &mut variables,
                 &mut functions,
                 &mut type_iterators,
+                &mut has_var_read_hooks,
             );
 
             self.flags
                 .set(ModuleFlags::INDEXED_GLOBAL_FUNCTIONS, has_global_functions);
+            self.flags
+                .set(ModuleFlags::VAR_READ_HOOKS, has_var_read_hooks);
 
             self.all_variables = (!variables.is_empty()).then_some(variables);
             self.all_functions = (!functions.is_empty()).then_some(functions);
@@ -2676,9 +5144,59 @@ impl Module {
     }
 }
 
+/// A handle to a [module][Module] that is only resolved when it is first accessed.
+///
+/// It is kept in a [`Scope`][crate::Scope] under the alias of the module.
+/// Clones share the same resolved [module][Module].
+#[cfg(not(feature = "no_module"))]
+#[derive(Debug, Clone)]
+pub(crate) struct DeferredImport {
+    /// Path of the [module][Module].
+    pub path: ImmutableString,
+    /// The [module][Module], once resolved.
+    pub module: Shared<crate::Locked<Option<SharedModule>>>,
+}
+
+mod script;
+
 /// Module containing all built-in [module resolvers][ModuleResolver].
 #[cfg(not(feature = "no_module"))]
 pub mod resolvers;
 
 #[cfg(not(feature = "no_module"))]
 pub use resolvers::ModuleResolver;
+
+/// Macro that registers many native Rust functions into a [`Module`] in one go.
+///
+/// It takes the [`Module`] followed by a list of `name => function` pairs, and calls
+/// [`Module::set_native_fn`] for each pair, so the number and types of parameters are inferred
+/// from each function as usual.  Pairs with the same name register overloads.
+///
+/// # Example
+///
+/// ```
+/// use rhai::{register_module_fns, Engine, Module, INT};
+///
+/// fn double(x: INT) -> Result<INT, Box<rhai::EvalAltResult>> { Ok(x * 2) }
+///
+/// let mut module = Module::new();
+///
+/// register_module_fns!(module, {
+///     "answer" => || Ok(42 as INT),
+///     "add" => |x: INT, y: INT| Ok(x + y),
+///     "add" => |x: INT, y: INT, z: INT| Ok(x + y + z),
+///     "double" => double,
+/// });
+///
+/// let mut engine = Engine::new();
+/// engine.register_global_module(module.into());
+///
+/// assert_eq!(engine.eval::<INT>("add(answer(), double(1), -2)").unwrap(), 42);
+/// ```
+#[macro_export]
+macro_rules! register_module_fns {
+    ($module:expr, { $($name:expr => $func:expr),* $(,)? }) => {{
+        let module: &mut $crate::Module = &mut $module;
+        $( module.set_native_fn($name, $func); )*
+    }};
+}
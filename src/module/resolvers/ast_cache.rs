@@ -0,0 +1,202 @@
+use crate::eval::GlobalRuntimeState;
+use crate::func::{get_hasher, locked_read, locked_write};
+use crate::{
+    Engine, Identifier, Locked, Module, ModuleResolver, Position, RhaiResultOf, Scope, Shared,
+    SharedModule, AST, ERR,
+};
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+use std::{
+    collections::BTreeMap,
+    hash::{Hash, Hasher},
+};
+
+/// A [module][Module] resolution service that serves [modules][Module] from script sources held in
+/// memory, caching the compiled [`AST`] of each source by a hash of its content.
+///
+/// Unlike other resolvers, the resolved [`Module`] itself is _not_ cached.  Each `import` evaluates
+/// the cached [`AST`] into a fresh [`Module`] (so module variables start from their initial
+/// values), but a script is only compiled again when its source text changes.
+///
+/// # Cache Eviction
+///
+/// Replacing the source of a path does not remove the [`AST`] compiled from the old source, so
+/// that switching back to an old source is cheap.  Use
+/// [`prune_cache`][AstCachingResolver::prune_cache] to drop all cached [`AST`]'s that no current
+/// source compiles to, or [`clear_cache`][AstCachingResolver::clear_cache] to drop all of them.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+/// use rhai::Engine;
+/// use rhai::module_resolvers::AstCachingResolver;
+///
+/// let mut resolver = AstCachingResolver::new();
+/// resolver.set_source("hello", "export let counter = 40; fn bump() { 2 }");
+///
+/// let mut engine = Engine::new();
+/// engine.set_module_resolver(resolver);
+///
+/// assert_eq!(engine.eval::<i64>(r#"import "hello" as h; h::counter + h::bump()"#)?, 42);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct AstCachingResolver {
+    /// Script sources (together with their hashes), keyed by path.
+    sources: BTreeMap<Identifier, (u64, String)>,
+    /// Compiled scripts, keyed by the hash of their sources.
+    ///
+    /// The cache is wrapped in interior mutability because [`resolve`][AstCachingResolver::resolve]
+    /// takes `&self`.
+    cache: Locked<BTreeMap<u64, Shared<AST>>>,
+}
+
+impl AstCachingResolver {
+    /// Create a new [`AstCachingResolver`].
+    #[inline(always)]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Set the script source for a path.
+    ///
+    /// If there is an existing source for the same path, it is replaced.
+    #[inline]
+    pub fn set_source(
+        &mut self,
+        path: impl Into<Identifier>,
+        source: impl Into<String>,
+    ) -> &mut Self {
+        let source = source.into();
+        let hasher = &mut get_hasher();
+        source.hash(hasher);
+        self.sources.insert(path.into(), (hasher.finish(), source));
+        self
+    }
+    /// Remove the script source for a path, returning it if any.
+    ///
+    /// The [`AST`] compiled from the source is not removed from the cache.
+    #[inline]
+    pub fn remove_source(&mut self, path: &str) -> Option<String> {
+        self.sources.remove(path).map(|(_, source)| source)
+    }
+    /// Does the path have a script source?
+    #[inline(always)]
+    #[must_use]
+    pub fn contains_path(&self, path: &str) -> bool {
+        self.sources.contains_key(path)
+    }
+    /// Is the compiled [`AST`] for the current source of a path cached?
+    #[inline]
+    #[must_use]
+    pub fn is_cached(&self, path: &str) -> bool {
+        self.sources.get(path).map_or(false, |(hash, _)| {
+            locked_read(&self.cache).unwrap().contains_key(hash)
+        })
+    }
+    /// Get the number of compiled [`AST`]'s in the cache.
+    #[inline]
+    #[must_use]
+    pub fn cache_len(&self) -> usize {
+        locked_read(&self.cache).unwrap().len()
+    }
+    /// Empty the cache of compiled [`AST`]'s.
+    #[inline]
+    pub fn clear_cache(&mut self) -> &mut Self {
+        locked_write(&self.cache).unwrap().clear();
+        self
+    }
+    /// Remove all compiled [`AST`]'s from the cache that do not belong to a current source.
+    #[inline]
+    pub fn prune_cache(&mut self) -> &mut Self {
+        let sources = &self.sources;
+        locked_write(&self.cache)
+            .unwrap()
+            .retain(|hash, _| sources.values().any(|(h, _)| h == hash));
+        self
+    }
+
+    /// Get the compiled [`AST`] for a path, compiling and caching it if necessary.
+    fn get_ast(&self, engine: &Engine, path: &str, pos: Position) -> RhaiResultOf<Shared<AST>> {
+        let (hash, source) = self
+            .sources
+            .get(path)
+            .ok_or_else(|| ERR::ErrorModuleNotFound(path.into(), pos))?;
+
+        if let Some(ast) = locked_read(&self.cache).unwrap().get(hash) {
+            return Ok(ast.clone());
+        }
+
+        let mut ast = engine
+            .compile(source)
+            .map_err(|err| ERR::ErrorInModule(path.into(), err.into(), pos))?;
+        ast.set_source(path);
+
+        let ast: Shared<_> = ast.into();
+        locked_write(&self.cache)
+            .unwrap()
+            .insert(*hash, ast.clone());
+
+        Ok(ast)
+    }
+}
+
+impl ModuleResolver for AstCachingResolver {
+    fn resolve_raw(
+        &self,
+        engine: &Engine,
+        global: &mut GlobalRuntimeState,
+        scope: &mut Scope,
+        path: &str,
+        pos: Position,
+    ) -> RhaiResultOf<SharedModule> {
+        let ast = self.get_ast(engine, path, pos)?;
+
+        // Guard against circular imports - sources are keyed by path
+        if global.importing.iter().any(|id| id == path) {
+            return Err(ERR::ErrorCircularImport(path.into(), pos).into());
+        }
+
+        global.importing.push(path.into());
+        let result = Module::eval_ast_as_new_raw(engine, scope, global, &ast);
+        global.importing.pop();
+
+        result
+            .map(Into::into)
+            .map_err(|err| ERR::ErrorInModule(path.into(), err, pos).into())
+    }
+
+    #[inline(always)]
+    fn resolve(
+        &self,
+        engine: &Engine,
+        _: Option<&str>,
+        path: &str,
+        pos: Position,
+    ) -> RhaiResultOf<SharedModule> {
+        let global = &mut engine.new_global_runtime_state();
+        let scope = &mut Scope::new();
+        self.resolve_raw(engine, global, scope, path, pos)
+    }
+
+    /// Resolve an `AST` based on a path string.
+    ///
+    /// The cached [`AST`] is returned if the source has not changed.
+    #[inline]
+    fn resolve_ast(
+        &self,
+        engine: &Engine,
+        _: Option<&str>,
+        path: &str,
+        pos: Position,
+    ) -> Option<RhaiResultOf<AST>> {
+        Some(self.get_ast(engine, path, pos).map(|ast| (*ast).clone()))
+    }
+
+    #[inline(always)]
+    fn can_resolve(&self, path: &str) -> bool {
+        self.contains_path(path)
+    }
+}
@@ -0,0 +1,209 @@
+use crate::eval::GlobalRuntimeState;
+use crate::func::{locked_read, locked_write};
+use crate::{
+    Engine, Identifier, Locked, ModuleResolver, Position, RhaiResultOf, Scope, SharedModule, AST,
+};
+use std::collections::BTreeMap;
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+/// A [module][crate::Module] resolution service that wraps another [module resolver][ModuleResolver]
+/// and caches the [modules][crate::Module] it resolves.
+///
+/// The first `import` of a path is delegated to the wrapped resolver; subsequent imports of the
+/// same path return the cached [module][crate::Module] without calling the wrapped resolver again.
+/// Failed resolutions are not cached.
+///
+/// The cache is keyed by the [cache key][ModuleResolver::cache_key] of the wrapped resolver, so
+/// that, for example, a relative path imported by scripts in different directories is cached once
+/// for each script file it resolves to.
+///
+/// # Example
+///
+/// ```
+/// use rhai::{Engine, Module};
+/// use rhai::module_resolvers::{CachingModuleResolver, StaticModuleResolver};
+///
+/// let mut resolver = StaticModuleResolver::new();
+/// resolver.insert("hello", Module::new());
+///
+/// let resolver = CachingModuleResolver::new(resolver);
+///
+/// let mut engine = Engine::new();
+/// engine.set_module_resolver(resolver);
+/// ```
+#[derive(Debug, Default)]
+pub struct CachingModuleResolver<R: ModuleResolver> {
+    /// The wrapped module resolver.
+    resolver: R,
+    /// Resolved modules, keyed by the cache keys of their paths.
+    ///
+    /// The cache is wrapped in interior mutability because
+    /// [`resolve`][CachingModuleResolver::resolve] takes `&self`.
+    cache: Locked<BTreeMap<Identifier, SharedModule>>,
+}
+
+impl<R: ModuleResolver> CachingModuleResolver<R> {
+    /// Create a new [`CachingModuleResolver`] wrapping a [module resolver][ModuleResolver].
+    #[inline(always)]
+    #[must_use]
+    pub fn new(resolver: R) -> Self {
+        Self {
+            resolver,
+            cache: BTreeMap::new().into(),
+        }
+    }
+    /// Get a reference to the wrapped [module resolver][ModuleResolver].
+    #[inline(always)]
+    #[must_use]
+    pub const fn resolver(&self) -> &R {
+        &self.resolver
+    }
+    /// Unwrap the wrapped [module resolver][ModuleResolver], dropping the cache.
+    #[inline(always)]
+    #[must_use]
+    pub fn into_inner(self) -> R {
+        self.resolver
+    }
+    /// Is a path, imported without a source, cached?
+    #[inline]
+    #[must_use]
+    pub fn is_cached(&self, path: &str) -> bool {
+        let key = self.resolver.cache_key(None, path);
+        locked_read(&self.cache).unwrap().contains_key(&key)
+    }
+    /// Remove a path, imported without a source, from the cache, returning the cached
+    /// [module][crate::Module] if any.
+    ///
+    /// The next time this path is imported, it is resolved by the wrapped resolver once again.
+    #[inline]
+    pub fn invalidate(&self, path: &str) -> Option<SharedModule> {
+        let key = self.resolver.cache_key(None, path);
+        locked_write(&self.cache).unwrap().remove(&key)
+    }
+    /// Empty the cache.
+    #[inline]
+    pub fn clear_cache(&self) {
+        locked_write(&self.cache).unwrap().clear();
+    }
+    /// Resolve and cache a list of paths ahead of time.
+    ///
+    /// Each path is resolved as if it were imported without a source, so subsequent `import`'s of
+    /// the same paths are served from the cache.
+    ///
+    /// All paths are attempted, even if some fail.  Failures are returned together with their
+    /// paths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::{Engine, Module};
+    /// use rhai::module_resolvers::{CachingModuleResolver, StaticModuleResolver};
+    ///
+    /// let mut resolver = StaticModuleResolver::new();
+    /// resolver.insert("hello", Module::new());
+    ///
+    /// let mut resolver = CachingModuleResolver::new(resolver);
+    /// let engine = Engine::new();
+    ///
+    /// let errors = resolver.warm(&engine, &["hello", "world"]).unwrap_err();
+    ///
+    /// assert!(resolver.is_cached("hello"));
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors[0].0, "world");
+    /// ```
+    pub fn warm(
+        &mut self,
+        engine: &Engine,
+        paths: &[&str],
+    ) -> Result<(), Vec<(String, Box<crate::EvalAltResult>)>> {
+        let errors: Vec<_> = paths
+            .iter()
+            .filter_map(|&path| {
+                self.resolve(engine, None, path, Position::NONE)
+                    .err()
+                    .map(|err| (path.to_string(), err))
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Get a cached [module][crate::Module], or resolve and cache it.
+    fn get_or_resolve(
+        &self,
+        key: Identifier,
+        resolve: impl FnOnce() -> RhaiResultOf<SharedModule>,
+    ) -> RhaiResultOf<SharedModule> {
+        if let Some(module) = locked_read(&self.cache).unwrap().get(&key) {
+            return Ok(module.clone());
+        }
+
+        let module = resolve()?;
+
+        locked_write(&self.cache)
+            .unwrap()
+            .insert(key, module.clone());
+
+        Ok(module)
+    }
+}
+
+impl<R: ModuleResolver> ModuleResolver for CachingModuleResolver<R> {
+    #[inline]
+    fn resolve_raw(
+        &self,
+        engine: &Engine,
+        global: &mut GlobalRuntimeState,
+        scope: &mut Scope,
+        path: &str,
+        pos: Position,
+    ) -> RhaiResultOf<SharedModule> {
+        let key = self.resolver.cache_key(global.source(), path);
+
+        self.get_or_resolve(key, || {
+            self.resolver.resolve_raw(engine, global, scope, path, pos)
+        })
+    }
+
+    #[inline]
+    fn resolve(
+        &self,
+        engine: &Engine,
+        source: Option<&str>,
+        path: &str,
+        pos: Position,
+    ) -> RhaiResultOf<SharedModule> {
+        let key = self.resolver.cache_key(source, path);
+
+        self.get_or_resolve(key, || self.resolver.resolve(engine, source, path, pos))
+    }
+
+    /// Resolve an `AST` based on a path string.
+    ///
+    /// This is always delegated to the wrapped resolver.
+    #[inline(always)]
+    fn resolve_ast(
+        &self,
+        engine: &Engine,
+        source: Option<&str>,
+        path: &str,
+        pos: Position,
+    ) -> Option<RhaiResultOf<AST>> {
+        self.resolver.resolve_ast(engine, source, path, pos)
+    }
+
+    #[inline]
+    fn can_resolve(&self, path: &str) -> bool {
+        self.is_cached(path) || self.resolver.can_resolve(path)
+    }
+
+    #[inline(always)]
+    fn cache_key(&self, source: Option<&str>, path: &str) -> Identifier {
+        self.resolver.cache_key(source, path)
+    }
+}
@@ -1,5 +1,6 @@
+use crate::eval::GlobalRuntimeState;
 use crate::{
-    Engine, ModuleResolver, Position, RhaiResultOf, SharedModule, StaticVec, ERR,
+    Engine, ModuleResolver, Position, RhaiResultOf, Scope, SharedModule, StaticVec, ERR,
     STATIC_VEC_INLINE_SIZE,
 };
 #[cfg(feature = "no_std")]
@@ -146,6 +147,36 @@ impl<M: ModuleResolver + 'static> AddAssign<M> for ModuleResolversCollection {
 }
 
 impl ModuleResolver for ModuleResolversCollection {
+    fn resolve_raw(
+        &self,
+        engine: &Engine,
+        global: &mut GlobalRuntimeState,
+        scope: &mut Scope,
+        path: &str,
+        pos: Position,
+    ) -> RhaiResultOf<SharedModule> {
+        let mut searched = Vec::new();
+
+        for resolver in &self.0 {
+            match resolver.resolve_raw(engine, global, scope, path, pos) {
+                Ok(module) => return Ok(module),
+                Err(err) => match *err {
+                    ERR::ErrorModuleNotFound(..) => continue,
+                    ERR::ErrorModuleFileNotFound(_, paths, _) => searched.extend(paths),
+                    ERR::ErrorInModule(_, err, _) => return Err(err),
+                    ERR::ErrorCircularImport(..) => return Err(err),
+                    _ => unreachable!("ModuleResolver::resolve_raw returns error that is not ErrorModuleNotFound, ErrorModuleFileNotFound, ErrorInModule or ErrorCircularImport"),
+                },
+            }
+        }
+
+        if searched.is_empty() {
+            Err(ERR::ErrorModuleNotFound(path.into(), pos).into())
+        } else {
+            Err(ERR::ErrorModuleFileNotFound(path.into(), searched, pos).into())
+        }
+    }
+
     fn resolve(
         &self,
         engine: &Engine,
@@ -153,17 +184,29 @@ impl ModuleResolver for ModuleResolversCollection {
         path: &str,
         pos: Position,
     ) -> RhaiResultOf<SharedModule> {
+        let mut searched = Vec::new();
+
         for resolver in &self.0 {
             match resolver.resolve(engine, source_path, path, pos) {
                 Ok(module) => return Ok(module),
                 Err(err) => match *err {
                     ERR::ErrorModuleNotFound(..) => continue,
+                    ERR::ErrorModuleFileNotFound(_, paths, _) => searched.extend(paths),
                     ERR::ErrorInModule(_, err, _) => return Err(err),
-                    _ => unreachable!("ModuleResolver::resolve returns error that is not ErrorModuleNotFound or ErrorInModule"),
+                    _ => unreachable!("ModuleResolver::resolve returns error that is not ErrorModuleNotFound, ErrorModuleFileNotFound or ErrorInModule"),
                 },
             }
         }
 
-        Err(ERR::ErrorModuleNotFound(path.into(), pos).into())
+        if searched.is_empty() {
+            Err(ERR::ErrorModuleNotFound(path.into(), pos).into())
+        } else {
+            Err(ERR::ErrorModuleFileNotFound(path.into(), searched, pos).into())
+        }
+    }
+
+    #[inline]
+    fn can_resolve(&self, path: &str) -> bool {
+        self.0.iter().any(|resolver| resolver.can_resolve(path))
     }
 }
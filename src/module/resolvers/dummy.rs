@@ -48,4 +48,9 @@ impl ModuleResolver for DummyModuleResolver {
     ) -> RhaiResultOf<SharedModule> {
         Err(ERR::ErrorModuleNotFound(path.into(), pos).into())
     }
+
+    #[inline(always)]
+    fn can_resolve(&self, _: &str) -> bool {
+        false
+    }
 }
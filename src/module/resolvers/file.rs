@@ -1,21 +1,32 @@
 #![cfg(not(feature = "no_std"))]
 #![cfg(any(not(target_family = "wasm"), not(target_os = "unknown")))]
 
+use crate::ast::{Expr, Stmt};
 use crate::eval::GlobalRuntimeState;
-use crate::func::{locked_read, locked_write};
+use crate::func::native::{OnDebugCallback, OnPrintCallback};
+use crate::func::{locked_read, locked_write, SendSync};
 use crate::{
-    Engine, Identifier, Locked, Module, ModuleResolver, Position, RhaiResultOf, Scope, Shared,
-    SharedModule, ERR,
+    CompileOptions, Engine, Identifier, ImmutableString, Locked, Module, ModuleResolver, Position,
+    RhaiResultOf, Scope, Shared, SharedModule, ERR,
 };
 
 use std::{
     collections::BTreeMap,
+    fmt,
     io::Error as IoError,
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
+    time::SystemTime,
 };
 
 pub const RHAI_SCRIPT_EXTENSION: &str = "rhai";
 
+/// Callback function for reading the contents of a script file.
+#[cfg(not(feature = "sync"))]
+type OnReadFileCallback = dyn Fn(&Path) -> std::io::Result<String>;
+/// Callback function for reading the contents of a script file.
+#[cfg(feature = "sync")]
+type OnReadFileCallback = dyn Fn(&Path) -> std::io::Result<String> + Send + Sync;
+
 /// A [module][Module] resolution service that loads [module][Module] script files from the file system.
 ///
 /// ## Caching
@@ -26,6 +37,18 @@ pub const RHAI_SCRIPT_EXTENSION: &str = "rhai";
 /// Use [`clear_cache`][FileModuleResolver::clear_cache] or
 /// [`clear_cache_for_path`][FileModuleResolver::clear_cache_for_path] to clear the internal cache.
 ///
+/// Use [`set_hot_reload`][FileModuleResolver::set_hot_reload] to reload script files automatically
+/// when they are modified.
+///
+/// ## Errors
+///
+/// When a script file cannot be found, resolution fails with
+/// [`ErrorModuleFileNotFound`][crate::EvalAltResult::ErrorModuleFileNotFound], which lists the
+/// full file paths (including extension) that were searched.
+///
+/// A script file that is rejected by [sandboxing][FileModuleResolver::with_sandbox] fails with
+/// [`ErrorModuleNotFound`][crate::EvalAltResult::ErrorModuleNotFound] instead.
+///
 /// ## Namespace
 ///
 /// When a function within a script file module is called, all functions defined within the same
@@ -46,10 +69,9 @@ pub const RHAI_SCRIPT_EXTENSION: &str = "rhai";
 ///
 /// engine.set_module_resolver(resolver);
 /// ```
-#[derive(Debug)]
 pub struct FileModuleResolver {
-    /// Base path of the directory holding script files.
-    base_path: Option<PathBuf>,
+    /// Base paths of the directories holding script files, searched in order.
+    base_paths: Vec<PathBuf>,
     /// File extension of script files, default `.rhai`.
     extension: Identifier,
     /// Is the cache enabled?
@@ -61,6 +83,53 @@ pub struct FileModuleResolver {
     /// The cache is wrapped in interior mutability because [`resolve`][FileModuleResolver::resolve]
     /// is immutable.
     cache: Locked<BTreeMap<PathBuf, SharedModule>>,
+    /// Reload script files that have been modified since they were cached?
+    hot_reload: bool,
+    /// Resolve `./` and `../` paths relative to the importing script?
+    relative_to_importer: bool,
+    /// Also try lower-case, upper-case and title-case variants of the file extension?
+    case_insensitive_extension: bool,
+    /// Modification times of cached script files, recorded for hot-reloading.
+    modified: Locked<BTreeMap<PathBuf, SystemTime>>,
+    /// Callback closure for implementing the `print` command while evaluating module scripts.
+    print: Option<Shared<OnPrintCallback>>,
+    /// Callback closure for implementing the `debug` command while evaluating module scripts.
+    debug: Option<Shared<OnDebugCallback>>,
+    /// Options for compiling module scripts.
+    compile_options: CompileOptions,
+    /// Evaluate only the declarations in module scripts?
+    dry_run: bool,
+    /// Reject script files outside the base path?
+    sandboxed: bool,
+    /// Custom closure for reading script files in place of the file system.
+    reader: Option<Shared<OnReadFileCallback>>,
+}
+
+impl fmt::Debug for FileModuleResolver {
+    #[cold]
+    #[inline(never)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileModuleResolver")
+            .field("base_paths", &self.base_paths)
+            .field("extension", &self.extension)
+            .field("cache_enabled", &self.cache_enabled)
+            .field("scope", &self.scope)
+            .field("cache", &self.cache)
+            .field("hot_reload", &self.hot_reload)
+            .field("relative_to_importer", &self.relative_to_importer)
+            .field(
+                "case_insensitive_extension",
+                &self.case_insensitive_extension,
+            )
+            .field("modified", &self.modified)
+            .field("print", &self.print.is_some())
+            .field("debug", &self.debug.is_some())
+            .field("compile_options", &self.compile_options)
+            .field("dry_run", &self.dry_run)
+            .field("sandboxed", &self.sandboxed)
+            .field("reader", &self.reader.is_some())
+            .finish()
+    }
 }
 
 impl Default for FileModuleResolver {
@@ -118,6 +187,31 @@ impl FileModuleResolver {
         Self::new_with_path_and_extension(path, RHAI_SCRIPT_EXTENSION)
     }
 
+    /// Create a new [`FileModuleResolver`] with a specific base path, rejecting any script file
+    /// that is not located under the base path.
+    ///
+    /// The default extension is `.rhai`.
+    ///
+    /// See [`with_sandbox`][FileModuleResolver::with_sandbox] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::Engine;
+    /// use rhai::module_resolvers::FileModuleResolver;
+    ///
+    /// // Create a new 'FileModuleResolver' loading scripts only from the 'scripts' subdirectory
+    /// let resolver = FileModuleResolver::new_with_path_sandboxed("./scripts");
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.set_module_resolver(resolver);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn new_with_path_sandboxed(path: impl Into<PathBuf>) -> Self {
+        Self::new_with_path(path).with_sandbox(true)
+    }
+
     /// Create a new [`FileModuleResolver`] with a file extension.
     ///
     /// # Example
@@ -136,11 +230,21 @@ impl FileModuleResolver {
     #[must_use]
     pub fn new_with_extension(extension: impl Into<Identifier>) -> Self {
         Self {
-            base_path: None,
+            base_paths: Vec::new(),
             extension: extension.into(),
             cache_enabled: true,
             cache: BTreeMap::new().into(),
+            hot_reload: false,
+            relative_to_importer: false,
+            case_insensitive_extension: false,
+            modified: BTreeMap::new().into(),
             scope: Scope::new(),
+            print: None,
+            debug: None,
+            compile_options: CompileOptions::new(),
+            dry_run: false,
+            sandboxed: false,
+            reader: None,
         }
     }
 
@@ -166,24 +270,68 @@ impl FileModuleResolver {
         extension: impl Into<Identifier>,
     ) -> Self {
         Self {
-            base_path: Some(path.into()),
+            base_paths: vec![path.into()],
             extension: extension.into(),
             cache_enabled: true,
             cache: BTreeMap::new().into(),
+            hot_reload: false,
+            relative_to_importer: false,
+            case_insensitive_extension: false,
+            modified: BTreeMap::new().into(),
             scope: Scope::new(),
+            print: None,
+            debug: None,
+            compile_options: CompileOptions::new(),
+            dry_run: false,
+            sandboxed: false,
+            reader: None,
         }
     }
 
     /// Get the base path for script files.
+    ///
+    /// If there are multiple base paths, this is the first one.
     #[inline(always)]
     #[must_use]
     pub fn base_path(&self) -> Option<&Path> {
-        self.base_path.as_deref()
+        self.base_paths.first().map(PathBuf::as_path)
     }
-    /// Set the base path for script files.
+    /// Set the base path for script files, replacing all existing base paths.
     #[inline(always)]
     pub fn set_base_path(&mut self, path: impl Into<PathBuf>) -> &mut Self {
-        self.base_path = Some(path.into());
+        self.base_paths.clear();
+        self.base_paths.push(path.into());
+        self
+    }
+    /// Get all the base paths for script files, in search order.
+    #[inline(always)]
+    #[must_use]
+    pub fn base_paths(&self) -> &[PathBuf] {
+        &self.base_paths
+    }
+    /// Add a base path for script files.
+    ///
+    /// Relative paths are searched under each base path in the order they are added, and the
+    /// first base path containing the script file wins.  If none do, resolution fails with
+    /// [`ErrorModuleFileNotFound`][crate::EvalAltResult::ErrorModuleFileNotFound], which lists all
+    /// the file paths searched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::Engine;
+    /// use rhai::module_resolvers::FileModuleResolver;
+    ///
+    /// // Search the 'lib' subdirectory first, then the 'scripts' subdirectory
+    /// let mut resolver = FileModuleResolver::new_with_path("./lib");
+    /// resolver.add_path("./scripts");
+    ///
+    /// let engine = Engine::new();
+    /// assert!(resolver.warm(&engine, &["loop"]).is_ok());
+    /// ```
+    #[inline(always)]
+    pub fn add_path(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.base_paths.push(path.into());
         self
     }
 
@@ -227,6 +375,318 @@ impl FileModuleResolver {
         &mut self.scope
     }
 
+    /// Override the action of `print` while evaluating module scripts loaded by this resolver.
+    ///
+    /// This handler is used in place of the [`Engine`]'s own `print` callback only during the
+    /// evaluation of a module script when it is first resolved.  Functions defined in the module
+    /// and called later still use the [`Engine`]'s callback.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::{Arc, RwLock};
+    /// use rhai::Engine;
+    /// use rhai::module_resolvers::FileModuleResolver;
+    ///
+    /// let output = Arc::new(RwLock::new(Vec::<String>::new()));
+    /// let logger = output.clone();
+    ///
+    /// let mut resolver = FileModuleResolver::new_with_path("./scripts");
+    /// resolver.on_print(move |s| logger.write().unwrap().push(s.to_string()));
+    ///
+    /// let engine = Engine::new();
+    /// resolver.warm(&engine, &["loop"]).unwrap();
+    ///
+    /// assert_eq!(output.read().unwrap().len(), 10);
+    /// ```
+    #[inline(always)]
+    pub fn on_print(&mut self, callback: impl Fn(&str) + SendSync + 'static) -> &mut Self {
+        self.print = Some(Shared::new(callback));
+        self
+    }
+
+    /// Override the action of `debug` while evaluating module scripts loaded by this resolver.
+    ///
+    /// This handler is used in place of the [`Engine`]'s own `debug` callback only during the
+    /// evaluation of a module script when it is first resolved.  Functions defined in the module
+    /// and called later still use the [`Engine`]'s callback.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::{Arc, RwLock};
+    /// use rhai::module_resolvers::FileModuleResolver;
+    ///
+    /// let output = Arc::new(RwLock::new(Vec::<String>::new()));
+    /// let logger = output.clone();
+    ///
+    /// let mut resolver = FileModuleResolver::new_with_path("./scripts");
+    /// resolver.on_debug(move |s, src, _| {
+    ///     logger.write().unwrap().push(format!("{}: {s}", src.unwrap_or("unknown")))
+    /// });
+    /// ```
+    #[inline(always)]
+    pub fn on_debug(
+        &mut self,
+        callback: impl Fn(&str, Option<&str>, Position) + SendSync + 'static,
+    ) -> &mut Self {
+        self.debug = Some(Shared::new(callback));
+        self
+    }
+
+    /// Set the [`CompileOptions`] used to compile module scripts, overriding parts of the
+    /// [`Engine`] configuration (e.g. to turn off optimization for large generated scripts).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::{CompileOptions, Engine};
+    /// use rhai::module_resolvers::FileModuleResolver;
+    ///
+    /// let options = CompileOptions::new().with_strict_variables(true);
+    /// let resolver = FileModuleResolver::new_with_path("./scripts").with_compile_options(options);
+    ///
+    /// assert!(resolver.compile_options().strict_variables);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn with_compile_options(mut self, options: CompileOptions) -> Self {
+        self.compile_options = options;
+        self
+    }
+    /// Get the [`CompileOptions`] used to compile module scripts.
+    #[inline(always)]
+    #[must_use]
+    pub const fn compile_options(&self) -> CompileOptions {
+        self.compile_options
+    }
+
+    /// Enable/disable dry-run evaluation of module scripts.
+    ///
+    /// In a dry run, only the declarations in a module script are evaluated, so the structure of
+    /// the resulting [module][Module] (its functions and exported variables) can be checked without
+    /// running any of its code.
+    ///
+    /// Top-level statements other than variable declarations and `export` statements (including
+    /// `import` statements) are skipped.
+    ///
+    /// # Limitations
+    ///
+    /// A variable keeps its value only if it is initialized with a constant expression.
+    /// Otherwise, the variable is declared with the value `()` and the initialization expression is
+    /// not evaluated, so computed values are not available.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::{Arc, RwLock};
+    /// use rhai::Engine;
+    /// use rhai::module_resolvers::FileModuleResolver;
+    ///
+    /// let output = Arc::new(RwLock::new(Vec::<String>::new()));
+    /// let logger = output.clone();
+    ///
+    /// let mut resolver = FileModuleResolver::new_with_path("./scripts").with_dry_run(true);
+    /// resolver.on_print(move |s| logger.write().unwrap().push(s.to_string()));
+    ///
+    /// let engine = Engine::new();
+    /// resolver.warm(&engine, &["loop"]).unwrap();
+    ///
+    /// // The loop in the module script is not run
+    /// assert!(output.read().unwrap().is_empty());
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn with_dry_run(mut self, enable: bool) -> Self {
+        self.dry_run = enable;
+        self
+    }
+    /// Is dry-run evaluation of module scripts enabled?
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Enable/disable sandboxing of script files under the base path.
+    ///
+    /// When enabled, the full path of each script file is canonicalized (resolving `..` segments
+    /// and symbolic links) and rejected with [`ErrorModuleNotFound`][crate::EvalAltResult::ErrorModuleNotFound]
+    /// unless it is located under the (also canonicalized) base path.  This prevents scripts from
+    /// loading arbitrary files via paths such as `"../secret"` or `"/etc/passwd"`.
+    ///
+    /// If there is no base path, the current directory is used instead.
+    ///
+    /// If a custom reader is set via [`with_reader`][FileModuleResolver::with_reader], paths are
+    /// normalized lexically instead (symbolic links are not resolved).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::Engine;
+    /// use rhai::module_resolvers::FileModuleResolver;
+    ///
+    /// let resolver = FileModuleResolver::new_with_path("./scripts").with_sandbox(true);
+    /// let engine = Engine::new();
+    ///
+    /// assert!(resolver.warm(&engine, &["loop"]).is_ok());
+    /// assert!(resolver.warm(&engine, &["../scripts/loop"]).is_ok());
+    /// assert!(resolver.warm(&engine, &["../src/lib"]).is_err());
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn with_sandbox(mut self, enable: bool) -> Self {
+        self.sandboxed = enable;
+        self
+    }
+    /// Is sandboxing of script files under the base path enabled?
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_sandboxed(&self) -> bool {
+        self.sandboxed
+    }
+
+    /// Read script files with a custom closure instead of from the file system.
+    ///
+    /// The closure is called with the full path of each script file, constructed from the base
+    /// path (or the source path) and the file extension as usual, and returns its contents.
+    /// This allows module scripts to be loaded from, say, an archive or an in-memory store.
+    ///
+    /// Returning an error of kind [`NotFound`][std::io::ErrorKind::NotFound] (or any other
+    /// [`std::io::Error`]) results in [`ErrorModuleNotFound`][crate::EvalAltResult::ErrorModuleNotFound].
+    ///
+    /// Sandboxing (see [`with_sandbox`][FileModuleResolver::with_sandbox]) still checks paths
+    /// against the real file system.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::io::{Error, ErrorKind};
+    /// use rhai::{Engine, INT};
+    /// use rhai::module_resolvers::FileModuleResolver;
+    ///
+    /// let resolver = FileModuleResolver::new_with_path("/virtual").with_reader(|path| {
+    ///     match path.to_str() {
+    ///         Some("/virtual/hello.rhai") => Ok("export const answer = 42;".into()),
+    ///         _ => Err(Error::from(ErrorKind::NotFound)),
+    ///     }
+    /// });
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.set_module_resolver(resolver);
+    ///
+    /// # #[cfg(unix)]
+    /// assert_eq!(engine.eval::<INT>(r#"import "hello" as m; m::answer"#).unwrap(), 42);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn with_reader(
+        mut self,
+        reader: impl Fn(&Path) -> std::io::Result<String> + SendSync + 'static,
+    ) -> Self {
+        self.reader = Some(Shared::new(reader));
+        self
+    }
+
+    /// Enable/disable resolving relative paths relative to the importing script.
+    ///
+    /// When enabled, an `import` path starting with `./` or `../` is resolved against the
+    /// directory of the script file doing the import.  For a module script loaded by this
+    /// resolver, that is the directory holding the module script; for the main script, it is the
+    /// directory of its source (e.g. as set by [`Engine::eval_file`][crate::Engine::eval_file]),
+    /// if any.  Other relative paths are searched for in the directory of the importing script
+    /// first, then under the base paths.
+    ///
+    /// To make this work, module scripts loaded by this resolver have their full file paths as
+    /// sources, instead of the `import` paths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::module_resolvers::FileModuleResolver;
+    ///
+    /// // 'import "./helpers"' inside './scripts/lib/util.rhai' loads './scripts/lib/helpers.rhai'.
+    /// // 'import "helpers"' does the same, but falls back to './scripts/helpers.rhai'.
+    /// let resolver = FileModuleResolver::new_with_path("./scripts").with_relative_to_importer(true);
+    ///
+    /// assert!(resolver.is_relative_to_importer());
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn with_relative_to_importer(mut self, enable: bool) -> Self {
+        self.relative_to_importer = enable;
+        self
+    }
+    /// Is resolving relative paths relative to the importing script enabled?
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_relative_to_importer(&self) -> bool {
+        self.relative_to_importer
+    }
+    /// Enable/disable matching the file extension case-insensitively.
+    ///
+    /// When enabled, if a script file with the configured extension does not exist, the
+    /// lower-case, upper-case and title-case variants of the extension are tried in turn (e.g.
+    /// `.rhai`, `.RHAI` and `.Rhai`), so that `Util.RHAI` is found for `import "Util"`.
+    /// If none exists, [`ErrorModuleFileNotFound`][crate::EvalAltResult::ErrorModuleFileNotFound]
+    /// lists all the file paths tried.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::module_resolvers::FileModuleResolver;
+    ///
+    /// let resolver = FileModuleResolver::new_with_path("./scripts")
+    ///                     .with_case_insensitive_extension(true);
+    ///
+    /// assert!(resolver.is_case_insensitive_extension());
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn with_case_insensitive_extension(mut self, enable: bool) -> Self {
+        self.case_insensitive_extension = enable;
+        self
+    }
+    /// Is matching the file extension case-insensitively enabled?
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_case_insensitive_extension(&self) -> bool {
+        self.case_insensitive_extension
+    }
+    /// Get the file extensions to try, in order.
+    #[must_use]
+    fn extensions(&self) -> Vec<String> {
+        let mut extensions = vec![self.extension.to_string()];
+
+        if self.case_insensitive_extension {
+            let lower = self.extension.to_lowercase();
+            let upper = lower.to_uppercase();
+            let mut chars = lower.chars();
+            let title = chars
+                .next()
+                .map(|ch| ch.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default();
+
+            for extension in [lower, upper, title] {
+                if !extensions.contains(&extension) {
+                    extensions.push(extension);
+                }
+            }
+        }
+
+        extensions
+    }
+    /// Should a path be resolved relative to the importing script only?
+    #[inline]
+    #[must_use]
+    fn is_importer_relative(&self, path: &str) -> bool {
+        self.relative_to_importer
+            && (path.starts_with("./")
+                || path.starts_with("../")
+                || path.starts_with(".\\")
+                || path.starts_with("..\\"))
+    }
+
     /// Enable/disable the cache.
     #[inline(always)]
     pub fn enable_cache(&mut self, enable: bool) -> &mut Self {
@@ -240,6 +700,50 @@ impl FileModuleResolver {
         self.cache_enabled
     }
 
+    /// Enable/disable hot-reloading of modified script files.
+    ///
+    /// The modification time of each script file is recorded when it is cached.  When enabled,
+    /// each subsequent resolution of the same file checks its modification time again, and the
+    /// script file is reloaded and recompiled if it has been modified since (even if it was cached
+    /// before hot-reloading was enabled).
+    ///
+    /// This is intended for live development.  It has no effect if the cache is disabled, and
+    /// script files whose modification time cannot be read (for example, those loaded via a
+    /// custom [reader][FileModuleResolver::with_reader] without a real file) are never reloaded.
+    #[inline(always)]
+    pub fn set_hot_reload(&mut self, enable: bool) -> &mut Self {
+        self.hot_reload = enable;
+        self
+    }
+    /// Is hot-reloading of modified script files enabled?
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_hot_reload(&self) -> bool {
+        self.hot_reload
+    }
+    /// Get the modification time of a script file.
+    ///
+    /// It is recorded whenever a script file is cached, even if hot-reloading is not (yet) enabled.
+    #[inline]
+    #[must_use]
+    fn modified_time(file_path: &Path) -> Option<SystemTime> {
+        std::fs::metadata(file_path).and_then(|m| m.modified()).ok()
+    }
+    /// Has a cached script file been modified since it was cached, if hot-reloading is enabled?
+    #[must_use]
+    fn is_modified(&self, file_path: &Path) -> bool {
+        if !self.hot_reload {
+            return false;
+        }
+        let Some(modified) = Self::modified_time(file_path) else {
+            return false;
+        };
+        locked_read(&self.modified)
+            .unwrap()
+            .get(file_path)
+            .map_or(false, |&cached| modified > cached)
+    }
+
     /// Is a particular path cached?
     #[inline]
     #[must_use]
@@ -255,6 +759,7 @@ impl FileModuleResolver {
     #[inline]
     pub fn clear_cache(&mut self) -> &mut Self {
         locked_write(&self.cache).unwrap().clear();
+        locked_write(&self.modified).unwrap().clear();
         self
     }
     /// Remove the specified path from internal cache.
@@ -263,32 +768,308 @@ impl FileModuleResolver {
     #[inline]
     #[must_use]
     pub fn clear_cache_for_path(&mut self, path: impl AsRef<Path>) -> Option<SharedModule> {
+        locked_write(&self.modified).unwrap().remove(path.as_ref());
         locked_write(&self.cache)
             .unwrap()
             .remove_entry(path.as_ref())
             .map(|(.., v)| v)
     }
+    /// Load, compile and cache a list of [modules][Module] ahead of time.
+    ///
+    /// Each path is resolved exactly as it would be by an `import` statement, so subsequent
+    /// `import`'s of the same paths are served from the internal cache.
+    ///
+    /// All paths are attempted, even if some fail.  Failures are returned together with their
+    /// paths.
+    ///
+    /// If the cache is disabled, modules are still loaded and compiled (which verifies them) but
+    /// nothing is kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::Engine;
+    /// use rhai::module_resolvers::FileModuleResolver;
+    ///
+    /// let resolver = FileModuleResolver::new_with_path("./scripts");
+    /// let engine = Engine::new();
+    ///
+    /// let errors = resolver.warm(&engine, &["loop", "does_not_exist"]).unwrap_err();
+    ///
+    /// assert!(resolver.is_cached(resolver.get_file_path("loop", None)));
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors[0].0, "does_not_exist");
+    /// ```
+    pub fn warm(
+        &self,
+        engine: &Engine,
+        paths: &[&str],
+    ) -> Result<(), Vec<(String, Box<crate::EvalAltResult>)>> {
+        let errors: Vec<_> = paths
+            .iter()
+            .filter_map(|&path| {
+                self.resolve(engine, None, path, Position::NONE)
+                    .err()
+                    .map(|err| (path.to_string(), err))
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
     /// Construct a full file path.
+    ///
+    /// If there are multiple base paths, relative paths are constructed under the first one.
+    ///
+    /// If resolving relative to the importing script is
+    /// [enabled][FileModuleResolver::with_relative_to_importer], `./` and `../` paths are
+    /// constructed under the source path, if any.  Other relative paths are still constructed
+    /// under the first base path, although [`search_paths`][FileModuleResolver::search_paths]
+    /// tries the source path first.
     #[must_use]
     pub fn get_file_path(&self, path: &str, source_path: Option<&Path>) -> PathBuf {
+        let importer_relative = self.is_importer_relative(path);
         let path = Path::new(path);
 
         let mut file_path;
 
-        if path.is_relative() {
+        if let (true, Some(source_path)) = (importer_relative, source_path) {
+            file_path = source_path.join(path);
+        } else if path.is_relative() {
             file_path = self
-                .base_path
-                .clone()
-                .or_else(|| source_path.map(Into::into))
+                .base_path()
+                .or(source_path)
+                .map(Path::to_path_buf)
                 .unwrap_or_default();
             file_path.push(path);
         } else {
             file_path = path.into();
         }
 
-        file_path.set_extension(self.extension.as_str()); // Force extension
+        force_extension(&mut file_path, &self.extension);
         file_path
     }
+    /// Get all the full file paths (including extension) that are searched, in order, for the
+    /// script file of a path.
+    ///
+    /// With zero or one base path, or for absolute paths, this is the single file path returned by
+    /// [`get_file_path`][FileModuleResolver::get_file_path] (unless the file extension is matched
+    /// case-insensitively, or a relative path is searched for next to the importing script first).
+    ///
+    /// These are the file paths listed by
+    /// [`ErrorModuleFileNotFound`][crate::EvalAltResult::ErrorModuleFileNotFound] when resolving a
+    /// [module][Module] fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::module_resolvers::FileModuleResolver;
+    /// use std::path::PathBuf;
+    ///
+    /// let mut resolver = FileModuleResolver::new_with_path("./scripts");
+    /// resolver.add_path("./lib");
+    ///
+    /// assert_eq!(
+    ///     resolver.search_paths("hello", None),
+    ///     [PathBuf::from("./scripts/hello.rhai"), PathBuf::from("./lib/hello.rhai")]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn search_paths(&self, path: &str, source_path: Option<&Path>) -> Vec<PathBuf> {
+        // Other relative paths are searched for next to the importing script first
+        let importer_path = source_path.filter(|_| {
+            self.relative_to_importer
+                && Path::new(path).is_relative()
+                && !self.is_importer_relative(path)
+        });
+
+        let single = importer_path.is_none()
+            && (self.base_paths.len() <= 1
+                || Path::new(path).is_absolute()
+                || (source_path.is_some() && self.is_importer_relative(path)));
+
+        if single && !self.case_insensitive_extension {
+            return vec![self.get_file_path(path, source_path)];
+        }
+
+        let file_paths = if single {
+            vec![self.get_file_path(path, source_path)]
+        } else {
+            let mut file_paths: Vec<_> = importer_path.map(|p| p.join(path)).into_iter().collect();
+
+            for base_path in &self.base_paths {
+                let file_path = base_path.join(path);
+
+                if !file_paths.contains(&file_path) {
+                    file_paths.push(file_path);
+                }
+            }
+
+            file_paths
+        };
+
+        let extensions = self.extensions();
+
+        file_paths
+            .into_iter()
+            .flat_map(|file_path| {
+                extensions.iter().map(move |extension| {
+                    let mut file_path = file_path.clone();
+                    force_extension(&mut file_path, extension);
+                    file_path
+                })
+            })
+            .collect()
+    }
+    /// Find the full file path of a script file, trying all the
+    /// [file paths searched][FileModuleResolver::search_paths] in order.
+    ///
+    /// A single file path is returned as is.  Otherwise, the first file path that is allowed and
+    /// is either cached or exists wins.  If none does, all the file paths searched are returned
+    /// as the error.
+    fn find_file_path(
+        &self,
+        path: &str,
+        source_path: Option<&Path>,
+    ) -> Result<PathBuf, Vec<PathBuf>> {
+        let mut file_paths = self.search_paths(path, source_path);
+
+        if file_paths.len() == 1 {
+            return Ok(file_paths.pop().unwrap());
+        }
+
+        file_paths
+            .iter()
+            .position(|file_path| {
+                self.is_allowed_path(file_path)
+                    && (self.is_cached(file_path)
+                        || match self.reader {
+                            Some(ref reader) => reader(file_path).is_ok(),
+                            None => file_path.is_file(),
+                        })
+            })
+            .map(|index| file_paths.swap_remove(index))
+            .ok_or(file_paths)
+    }
+    /// Create an [`ErrorModuleFileNotFound`][ERR::ErrorModuleFileNotFound] listing the file paths
+    /// searched.
+    fn module_not_found(path: &str, file_paths: &[PathBuf], pos: Position) -> Box<ERR> {
+        let file_paths = file_paths
+            .iter()
+            .map(|file_path| file_path.to_string_lossy().to_string())
+            .collect();
+
+        ERR::ErrorModuleFileNotFound(path.to_string(), file_paths, pos).into()
+    }
+    /// Is a full file path allowed to be loaded?
+    ///
+    /// Always `true` unless sandboxing is enabled, in which case the canonicalized file path must
+    /// be located under one of the canonicalized base paths (or the current directory).
+    ///
+    /// When a custom reader is set, paths may not exist on the file system, so they are
+    /// normalized lexically instead of being canonicalized.
+    #[must_use]
+    fn is_allowed_path(&self, file_path: &Path) -> bool {
+        if !self.sandboxed {
+            return true;
+        }
+
+        if self.reader.is_some() {
+            let file_path = normalize_path(file_path);
+            let is_under = |base_path: &Path| {
+                file_path
+                    .strip_prefix(normalize_path(base_path))
+                    .map_or(false, |rest| {
+                        !rest.components().any(|c| c == Component::ParentDir)
+                    })
+            };
+
+            if self.base_paths.is_empty() {
+                return is_under(Path::new("."));
+            }
+
+            return self.base_paths.iter().any(|base_path| is_under(base_path));
+        }
+
+        let Ok(file_path) = file_path.canonicalize() else {
+            return false;
+        };
+
+        if self.base_paths.is_empty() {
+            return Path::new(".")
+                .canonicalize()
+                .map_or(false, |base_path| file_path.starts_with(base_path));
+        }
+
+        self.base_paths.iter().any(|base_path| {
+            base_path
+                .canonicalize()
+                .map_or(false, |base_path| file_path.starts_with(base_path))
+        })
+    }
+
+    /// Get the identity of a full file path, used to detect circular imports.
+    ///
+    /// The file path is canonicalized, unless a custom [reader][FileModuleResolver::with_reader]
+    /// is set (the file may not exist in the file system), in which case it is only normalized
+    /// lexically.
+    #[must_use]
+    fn module_identity(&self, file_path: &Path) -> ImmutableString {
+        let file_path = match self.reader {
+            Some(..) => normalize_path(file_path),
+            None => file_path
+                .canonicalize()
+                .unwrap_or_else(|_| normalize_path(file_path)),
+        };
+
+        file_path.to_string_lossy().into()
+    }
+
+    /// Load and compile a script file into an [`AST`][crate::AST].
+    fn compile_script(
+        &self,
+        engine: &Engine,
+        scope: &Scope,
+        path: &str,
+        file_path: PathBuf,
+        pos: Position,
+    ) -> RhaiResultOf<crate::AST> {
+        // Nested `./` and `../` imports are resolved against the source of the module script
+        let source = if self.relative_to_importer {
+            file_path.to_string_lossy().to_string()
+        } else {
+            path.to_string()
+        };
+
+        let result = match self.reader {
+            Some(ref reader) => reader(&file_path)
+                .map_err(|err| {
+                    Box::new(ERR::ErrorSystem(
+                        format!("Cannot read script file '{}'", file_path.to_string_lossy()),
+                        err.into(),
+                    ))
+                })
+                .and_then(|contents| {
+                    engine.compile_with_options(scope, contents, self.compile_options)
+                }),
+            None => {
+                engine.compile_file_with_options(scope, file_path.clone(), self.compile_options)
+            }
+        };
+
+        let mut ast = result.map_err(|err| match *err {
+            ERR::ErrorSystem(.., err) if err.is::<IoError>() => {
+                Self::module_not_found(path, &[file_path], pos)
+            }
+            _ => Box::new(ERR::ErrorInModule(path.to_string(), err, pos)),
+        })?;
+
+        ast.set_source(source);
+        Ok(ast)
+    }
 
     /// Resolve a module based on a path.
     fn impl_resolve(
@@ -306,30 +1087,80 @@ impl FileModuleResolver {
             .or(source)
             .and_then(|p| Path::new(p).parent());
 
-        let file_path = self.get_file_path(path, source_path);
+        let file_path = self
+            .find_file_path(path, source_path)
+            .map_err(|file_paths| Self::module_not_found(path, &file_paths, pos))?;
 
-        if self.is_cache_enabled() {
+        if !self.is_allowed_path(&file_path) {
+            return Err(ERR::ErrorModuleNotFound(path.to_string(), pos).into());
+        }
+
+        if self.is_cache_enabled() && !self.is_modified(&file_path) {
             if let Some(module) = locked_read(&self.cache).unwrap().get(&file_path) {
                 return Ok(module.clone());
             }
         }
 
-        let mut ast = engine
-            .compile_file_with_scope(&self.scope, file_path.clone())
-            .map_err(|err| match *err {
-                ERR::ErrorSystem(.., err) if err.is::<IoError>() => {
-                    Box::new(ERR::ErrorModuleNotFound(path.to_string(), pos))
-                }
-                _ => Box::new(ERR::ErrorInModule(path.to_string(), err, pos)),
-            })?;
+        // Guard against circular imports
+        let id = self.module_identity(&file_path);
+
+        if global.importing.contains(&id) {
+            return Err(ERR::ErrorCircularImport(path.to_string(), pos).into());
+        }
+
+        // Record the modification time before loading, so changes during loading are not missed
+        let modified = if self.is_cache_enabled() {
+            Self::modified_time(&file_path)
+        } else {
+            None
+        };
+
+        let mut ast = self.compile_script(engine, &self.scope, path, file_path.clone(), pos)?;
 
-        ast.set_source(path);
+        if self.dry_run {
+            retain_declarations(&mut ast);
+        }
+
+        // Install the print and debug handlers for the duration of the module evaluation
+        let orig_print = match self.print {
+            Some(ref print) => global.print.replace(print.clone()),
+            None => global.print.clone(),
+        };
+        let orig_debug = match self.debug {
+            Some(ref debug) => global.debug.replace(debug.clone()),
+            None => global.debug.clone(),
+        };
+
+        // Make the prelude available to the module script
+        let orig_scope_len = scope.len();
+        let orig_imports_len = global.num_imports();
+
+        if let Some(ref prelude) = engine.module_prelude {
+            for (name, value) in prelude.iter_var() {
+                scope.push_constant_dynamic(name, value.clone());
+            }
+            global.push_import("", prelude.clone());
+        }
 
-        let m: Shared<_> = Module::eval_ast_as_new_raw(engine, scope, global, &ast)
+        global.importing.push(id);
+        let result = Module::eval_ast_as_new_raw(engine, scope, global, &ast);
+        global.importing.pop();
+
+        global.truncate_imports(orig_imports_len);
+        scope.rewind(orig_scope_len);
+        global.print = orig_print;
+        global.debug = orig_debug;
+
+        let m: Shared<_> = result
             .map_err(|err| Box::new(ERR::ErrorInModule(path.to_string(), err, pos)))?
             .into();
 
         if self.is_cache_enabled() {
+            if let Some(modified) = modified {
+                locked_write(&self.modified)
+                    .unwrap()
+                    .insert(file_path.clone(), modified);
+            }
             locked_write(&self.cache)
                 .unwrap()
                 .insert(file_path, m.clone());
@@ -375,22 +1206,118 @@ impl ModuleResolver for FileModuleResolver {
         pos: Position,
     ) -> Option<RhaiResultOf<crate::AST>> {
         // Construct the script file path
-        let file_path = self.get_file_path(path, source_path.map(Path::new));
+        let file_path = match self.find_file_path(path, source_path.map(Path::new)) {
+            Ok(file_path) => file_path,
+            Err(file_paths) => return Some(Err(Self::module_not_found(path, &file_paths, pos))),
+        };
+
+        if !self.is_allowed_path(&file_path) {
+            return Some(Err(ERR::ErrorModuleNotFound(path.to_string(), pos).into()));
+        }
 
         // Load the script file and compile it
-        Some(
-            engine
-                .compile_file(file_path)
-                .map(|mut ast| {
-                    ast.set_source(path);
-                    ast
-                })
-                .map_err(|err| match *err {
-                    ERR::ErrorSystem(.., err) if err.is::<IoError>() => {
-                        ERR::ErrorModuleNotFound(path.to_string(), pos).into()
-                    }
-                    _ => ERR::ErrorInModule(path.to_string(), err, pos).into(),
-                }),
-        )
+        Some(self.compile_script(engine, &Scope::new(), path, file_path, pos))
+    }
+
+    /// Does the script file for a path exist (or is the module already cached)?
+    ///
+    /// Relative paths are resolved against the base paths, if any, because there is no source
+    /// path to resolve them against.  The script file is not loaded, unless a custom reader is
+    /// set (see [`with_reader`][FileModuleResolver::with_reader]), in which case it is read but
+    /// not compiled.
+    fn can_resolve(&self, path: &str) -> bool {
+        let Ok(file_path) = self.find_file_path(path, None) else {
+            return false;
+        };
+
+        if !self.is_allowed_path(&file_path) {
+            return false;
+        }
+        if self.is_cached(&file_path) {
+            return true;
+        }
+
+        match self.reader {
+            Some(ref reader) => reader(&file_path).is_ok(),
+            None => file_path.is_file(),
+        }
     }
+
+    /// Get the identity of the script file that a path resolves to, given the source of the
+    /// import, if any.
+    ///
+    /// A relative path imported by scripts in different directories may resolve to different
+    /// script files, and so to different keys.
+    fn cache_key(&self, source: Option<&str>, path: &str) -> Identifier {
+        let source_path = source.and_then(|p| Path::new(p).parent());
+        let file_path = self
+            .find_file_path(path, source_path)
+            .unwrap_or_else(|_| self.get_file_path(path, source_path));
+
+        self.module_identity(&file_path).as_str().into()
+    }
+}
+
+/// Force a file extension onto the final component of a file path.
+///
+/// An existing extension is replaced only if it is the same as the file extension (ignoring case),
+/// so that `"hello.rhai"` does not become `"hello.rhai.rhai"`.  Otherwise the file extension is
+/// appended, so that dots in the final component (e.g. `"my.module"`) are kept.
+fn force_extension(file_path: &mut PathBuf, extension: &str) {
+    match (file_path.extension(), file_path.file_name()) {
+        (Some(ext), Some(file_name))
+            if !extension.is_empty() && !ext.eq_ignore_ascii_case(extension) =>
+        {
+            let mut file_name = file_name.to_os_string();
+            file_name.push(".");
+            file_name.push(extension);
+            file_path.set_file_name(file_name);
+        }
+        _ => {
+            file_path.set_extension(extension);
+        }
+    }
+}
+
+/// Normalize a file path lexically, removing `.` components and resolving `..` components against
+/// the preceding component where there is one.
+///
+/// The file system is not accessed, so symbolic links are not resolved.
+#[must_use]
+fn normalize_path(file_path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+
+    for component in file_path.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir
+                if matches!(result.components().next_back(), Some(Component::Normal(..))) =>
+            {
+                result.pop();
+            }
+            Component::ParentDir if result.has_root() => (),
+            component => result.push(component),
+        }
+    }
+
+    result
+}
+
+/// Remove all top-level statements from an [`AST`][crate::AST] except variable declarations and
+/// `export` statements, replacing non-constant initial values with `()`.
+fn retain_declarations(ast: &mut crate::AST) {
+    let statements = std::mem::take(ast.statements_mut());
+
+    *ast.statements_mut() = statements
+        .into_iter()
+        .filter_map(|stmt| match stmt {
+            Stmt::Var(x, options, pos) if !x.1.is_constant() => {
+                let (name, expr, index) = *x;
+                let expr = Expr::Unit(expr.position());
+                Some(Stmt::Var((name, expr, index).into(), options, pos))
+            }
+            Stmt::Var(..) | Stmt::Export(..) => Some(stmt),
+            _ => None,
+        })
+        .collect();
 }
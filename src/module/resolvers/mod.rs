@@ -1,20 +1,26 @@
 use crate::eval::GlobalRuntimeState;
 use crate::func::SendSync;
-use crate::{Engine, Position, RhaiResultOf, Scope, SharedModule, AST};
+use crate::{Engine, Identifier, Position, RhaiResultOf, Scope, SharedModule, AST};
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 
+mod ast_cache;
+mod caching;
 mod collection;
 mod dummy;
 mod file;
 mod stat;
+mod versioned;
 
+pub use ast_cache::AstCachingResolver;
+pub use caching::CachingModuleResolver;
 pub use collection::ModuleResolversCollection;
 pub use dummy::DummyModuleResolver;
 #[cfg(not(feature = "no_std"))]
 #[cfg(any(not(target_family = "wasm"), not(target_os = "unknown")))]
 pub use file::FileModuleResolver;
 pub use stat::StaticModuleResolver;
+pub use versioned::{ModuleVersion, VersionedModuleResolver};
 
 /// Trait that encapsulates a module resolution service.
 pub trait ModuleResolver: SendSync {
@@ -32,6 +38,11 @@ pub trait ModuleResolver: SendSync {
     /// # WARNING - Low Level API
     ///
     /// This function is very low level.
+    ///
+    /// Override the default implementation of this method if the module resolver evaluates
+    /// scripts, and evaluate them with the provided [`GlobalRuntimeState`], so that circular
+    /// imports can be detected.  [`resolve`][ModuleResolver::resolve] starts from a fresh
+    /// [`GlobalRuntimeState`], which does not know about the modules being imported.
     #[allow(unused_variables)]
     fn resolve_raw(
         &self,
@@ -64,4 +75,36 @@ pub trait ModuleResolver: SendSync {
     ) -> Option<RhaiResultOf<AST>> {
         None
     }
+
+    /// Would a path string resolve to a module?
+    ///
+    /// This is a cheap pre-flight check: no module is loaded, compiled or evaluated.
+    ///
+    /// The default implementation returns `true`, i.e. the path is assumed to be resolvable.
+    ///
+    /// Override the default implementation of this method if the module resolver can check for
+    /// the existence of a module without side effects.
+    #[allow(unused_variables)]
+    #[inline(always)]
+    #[must_use]
+    fn can_resolve(&self, path: &str) -> bool {
+        true
+    }
+
+    /// Get a key that identifies the module a path string resolves to, given the source of the
+    /// import, if any.
+    ///
+    /// This is used to cache resolved modules (see
+    /// [`CachingModuleResolver`][crate::module_resolvers::CachingModuleResolver]).
+    ///
+    /// The default implementation returns the path string itself.
+    ///
+    /// Override the default implementation of this method if the same path string may resolve to
+    /// different modules depending on the source of the import (e.g. relative file paths).
+    #[allow(unused_variables)]
+    #[inline(always)]
+    #[must_use]
+    fn cache_key(&self, source: Option<&str>, path: &str) -> Identifier {
+        path.into()
+    }
 }
@@ -1,15 +1,67 @@
+use crate::func::native::OnBuildModuleCallback;
+use crate::func::{locked_read, locked_write, SendSync};
 use crate::{
-    Engine, Identifier, Module, ModuleResolver, Position, RhaiResultOf, SharedModule, SmartString,
-    ERR,
+    Engine, Identifier, Locked, Module, ModuleResolver, Position, RhaiResultOf, Shared,
+    SharedModule, SmartString, ERR,
 };
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 use std::{
     collections::btree_map::{IntoIter, Iter},
     collections::BTreeMap,
+    fmt,
+    iter::{Extend, FromIterator},
     ops::AddAssign,
 };
 
+/// A [module][Module] that is constructed by a factory on first use.
+struct LazyModule {
+    /// The factory that constructs the [module][Module].
+    factory: Box<OnBuildModuleCallback>,
+    /// The [module][Module] once constructed.
+    module: Locked<Option<SharedModule>>,
+}
+
+impl fmt::Debug for LazyModule {
+    #[cold]
+    #[inline(never)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyModule")
+            .field("module", &self.module)
+            .finish()
+    }
+}
+
+impl LazyModule {
+    /// Get the [module][Module], constructing it if this is the first time.
+    ///
+    /// The lock is not held while the factory runs, so the factory can itself resolve other
+    /// [modules][Module].  If the [module][Module] is constructed on multiple threads at the same
+    /// time, the first one stored is kept.
+    fn get(&self, path: &str) -> SharedModule {
+        if let Some(module) = self.get_built() {
+            return module;
+        }
+
+        let mut module = (self.factory)();
+
+        if module.id().is_none() {
+            module.set_id(path);
+        }
+
+        module.build_index();
+
+        locked_write(&self.module)
+            .unwrap()
+            .get_or_insert_with(|| module.into())
+            .clone()
+    }
+    /// Get the [module][Module] if it has been constructed.
+    fn get_built(&self) -> Option<SharedModule> {
+        locked_read(&self.module).unwrap().clone()
+    }
+}
+
 /// A static [module][Module] resolution service that serves [modules][Module] added into it.
 ///
 /// # Example
@@ -28,7 +80,11 @@ use std::{
 /// engine.set_module_resolver(resolver);
 /// ```
 #[derive(Debug, Clone, Default)]
-pub struct StaticModuleResolver(BTreeMap<Identifier, SharedModule>);
+pub struct StaticModuleResolver(
+    BTreeMap<Identifier, SharedModule>,
+    BTreeMap<Identifier, Identifier>,
+    BTreeMap<Identifier, Shared<LazyModule>>,
+);
 
 impl StaticModuleResolver {
     /// Create a new [`StaticModuleResolver`].
@@ -50,11 +106,77 @@ impl StaticModuleResolver {
     #[inline(always)]
     #[must_use]
     pub const fn new() -> Self {
-        Self(BTreeMap::new())
+        Self(BTreeMap::new(), BTreeMap::new(), BTreeMap::new())
+    }
+    /// Create a new [`StaticModuleResolver`] from a list of (path, script source) pairs.
+    ///
+    /// Each script is compiled and evaluated with the [`Engine`] into a [module][Module] keyed by
+    /// its path.
+    ///
+    /// The first script that fails to compile or evaluate stops the process, and its path is
+    /// returned together with the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::Engine;
+    /// use rhai::module_resolvers::StaticModuleResolver;
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// let resolver = StaticModuleResolver::from_sources(&engine, &[
+    ///     ("hello", "export const ANSWER = 42;"),
+    ///     ("world", "fn calc(x) { x + 1 }"),
+    /// ]).map_err(|(_, err)| err)?;
+    ///
+    /// engine.set_module_resolver(resolver);
+    ///
+    /// assert_eq!(engine.eval::<i64>(r#"import "hello" as h; h::ANSWER"#)?, 42);
+    /// assert_eq!(engine.eval::<i64>(r#"import "world" as w; w::calc(41)"#)?, 42);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_sources(
+        engine: &Engine,
+        sources: &[(&str, &str)],
+    ) -> Result<Self, (String, Box<crate::EvalAltResult>)> {
+        let mut resolver = Self::new();
+
+        for &(path, source) in sources {
+            let module = engine
+                .compile(source)
+                .map_err(Into::into)
+                .and_then(|mut ast| {
+                    ast.set_source(path);
+                    Module::eval_ast_as_new(crate::Scope::new(), &ast, engine)
+                })
+                .map_err(|err| (path.to_string(), err))?;
+
+            resolver.insert(path, module);
+        }
+
+        Ok(resolver)
     }
     /// Add a [module][Module] keyed by its path.
+    ///
+    /// Any lazily-constructed [module][Module] under the same path is replaced.
+    ///
+    /// Returns `&mut Self` so that calls can be chained.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::Module;
+    /// use rhai::module_resolvers::StaticModuleResolver;
+    ///
+    /// let mut resolver = StaticModuleResolver::new();
+    /// resolver.insert("hello", Module::new()).insert("world", Module::new());
+    ///
+    /// assert_eq!(resolver.len(), 2);
+    /// ```
     #[inline]
-    pub fn insert(&mut self, path: impl Into<Identifier>, mut module: Module) {
+    pub fn insert(&mut self, path: impl Into<Identifier>, mut module: Module) -> &mut Self {
         let path = path.into();
 
         if module.id().is_none() {
@@ -62,18 +184,150 @@ impl StaticModuleResolver {
         }
 
         module.build_index();
+        self.2.remove(&path);
         self.0.insert(path, module.into());
+        self
+    }
+    /// Add a [module][Module] keyed by its path, to be constructed by a factory only when it is
+    /// first resolved.
+    ///
+    /// This avoids constructing expensive [modules][Module] that may never be imported.  The
+    /// constructed [module][Module] is kept and returned for all subsequent resolutions (including
+    /// those via clones of this [`StaticModuleResolver`]).  The factory normally runs once, but
+    /// may run more than once if the [module][Module] is first resolved on multiple threads at the
+    /// same time.
+    ///
+    /// Any [module][Module] under the same path is replaced.
+    ///
+    /// [`len`][StaticModuleResolver::len], [`is_empty`][StaticModuleResolver::is_empty],
+    /// [`iter`][StaticModuleResolver::iter], [`iter_mut`][StaticModuleResolver::iter_mut],
+    /// [`values`][StaticModuleResolver::values] and [`into_iter`][IntoIterator::into_iter] do not
+    /// include lazily-constructed [modules][Module], which are only constructed when resolved.
+    /// [`paths`][StaticModuleResolver::paths] and
+    /// [`contains_path`][StaticModuleResolver::contains_path] do.
+    ///
+    /// Returns `&mut Self` so that calls can be chained.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Module};
+    /// use rhai::module_resolvers::StaticModuleResolver;
+    ///
+    /// let mut resolver = StaticModuleResolver::new();
+    ///
+    /// resolver.insert_lazy("hello", || {
+    ///     let mut module = Module::new();
+    ///     module.set_var("answer", 42_i64);
+    ///     module
+    /// });
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.set_module_resolver(resolver);
+    ///
+    /// assert_eq!(engine.eval::<i64>(r#"import "hello" as h; h::answer"#)?, 42);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn insert_lazy(
+        &mut self,
+        path: impl Into<Identifier>,
+        factory: impl Fn() -> Module + SendSync + 'static,
+    ) -> &mut Self {
+        let path = path.into();
+
+        let lazy = LazyModule {
+            factory: Box::new(factory),
+            module: None.into(),
+        };
+
+        self.0.remove(&path);
+        self.2.insert(path, lazy.into());
+        self
     }
     /// Remove a [module][Module] given its path.
+    ///
+    /// For a lazily-constructed [module][Module], [`None`] is returned if it has not yet been
+    /// constructed.
     #[inline(always)]
     pub fn remove(&mut self, path: &str) -> Option<SharedModule> {
-        self.0.remove(path)
+        self.0
+            .remove(path)
+            .or_else(|| self.2.remove(path).and_then(|lazy| lazy.get_built()))
+    }
+    /// Add an alias path that resolves to the [module][Module] under another path.
+    ///
+    /// Aliases are checked before the [modules][Module] themselves and may point to other aliases.
+    /// An alias chain that loops back onto itself resolves to nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Module};
+    /// use rhai::module_resolvers::StaticModuleResolver;
+    ///
+    /// let mut module = Module::new();
+    /// module.set_var("answer", 42_i64);
+    ///
+    /// let mut resolver = StaticModuleResolver::new();
+    /// resolver.insert("hello", module);
+    /// resolver.add_alias("hi", "hello");
+    ///
+    /// let mut engine = Engine::new();
+    /// engine.set_module_resolver(resolver);
+    ///
+    /// assert_eq!(engine.eval::<i64>(r#"import "hi" as h; h::answer"#)?, 42);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn add_alias(&mut self, alias: impl Into<Identifier>, target: impl Into<Identifier>) {
+        self.1.insert(alias.into(), target.into());
     }
-    /// Does the path exist?
+    /// Remove an alias path.
     #[inline(always)]
+    pub fn remove_alias(&mut self, alias: &str) -> Option<Identifier> {
+        self.1.remove(alias)
+    }
+    /// Follow aliases from a path to the path of a [module][Module].
+    #[must_use]
+    fn target_path<'a>(&'a self, path: &'a str) -> Option<&'a str> {
+        let mut path = path;
+
+        // Each step follows one alias, so a longer chain must contain a cycle
+        for _ in 0..=self.1.len() {
+            match self.1.get(path) {
+                Some(target) => path = target,
+                None => return Some(path),
+            }
+        }
+
+        None
+    }
+    /// Get the [module][Module] for a path, following aliases.
+    ///
+    /// A lazily-constructed [module][Module] is constructed if this is the first time.
+    #[must_use]
+    fn get(&self, path: &str) -> Option<SharedModule> {
+        let path = self.target_path(path)?;
+
+        self.0
+            .get(path)
+            .cloned()
+            .or_else(|| self.2.get(path).map(|lazy| lazy.get(path)))
+    }
+    /// Does the path (or alias path) exist?
+    ///
+    /// Lazily-constructed [modules][Module] are not constructed.
+    #[inline]
     #[must_use]
     pub fn contains_path(&self, path: &str) -> bool {
-        self.0.contains_key(path)
+        self.target_path(path).map_or(false, |path| {
+            self.0.contains_key(path) || self.2.contains_key(path)
+        })
     }
     /// Get an iterator of all the [modules][Module].
     #[inline]
@@ -85,10 +339,11 @@ impl StaticModuleResolver {
     pub fn iter_mut(&mut self) -> impl Iterator<Item = (&str, &mut SharedModule)> {
         self.0.iter_mut().map(|(k, v)| (k.as_str(), v))
     }
-    /// Get an iterator of all the [module][Module] paths.
+    /// Get an iterator of all the [module][Module] paths, including those of lazily-constructed
+    /// [modules][Module].
     #[inline]
     pub fn paths(&self) -> impl Iterator<Item = &str> {
-        self.0.keys().map(SmartString::as_str)
+        self.0.keys().chain(self.2.keys()).map(SmartString::as_str)
     }
     /// Get an iterator of all the [modules][Module].
     #[inline(always)]
@@ -99,15 +354,24 @@ impl StaticModuleResolver {
     #[inline(always)]
     pub fn clear(&mut self) -> &mut Self {
         self.0.clear();
+        self.1.clear();
+        self.2.clear();
         self
     }
     /// Returns `true` if this [`StaticModuleResolver`] contains no module resolvers.
+    ///
+    /// Lazily-constructed [modules][Module] are included, so this may be `false` even when
+    /// [`len`][StaticModuleResolver::len] is zero.
     #[inline(always)]
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.0.is_empty() && self.2.is_empty()
     }
     /// Get the number of [modules][Module] in this [`StaticModuleResolver`].
+    ///
+    /// Lazily-constructed [modules][Module] are not included, the same as for
+    /// [`iter`][StaticModuleResolver::iter].  Use [`paths`][StaticModuleResolver::paths] to count
+    /// them as well.
     #[inline(always)]
     #[must_use]
     pub fn len(&self) -> usize {
@@ -116,10 +380,18 @@ impl StaticModuleResolver {
     /// Merge another [`StaticModuleResolver`] into this.
     /// The other [`StaticModuleResolver`] is consumed.
     ///
-    /// Existing modules of the same path name are overwritten.
+    /// Existing modules and aliases of the same path name are overwritten.
     #[inline]
     pub fn merge(&mut self, other: Self) -> &mut Self {
+        for path in other.0.keys() {
+            self.2.remove(path);
+        }
+        for path in other.2.keys() {
+            self.0.remove(path);
+        }
         self.0.extend(other.0);
+        self.1.extend(other.1);
+        self.2.extend(other.2);
         self
     }
 }
@@ -145,6 +417,24 @@ impl<'a> IntoIterator for &'a StaticModuleResolver {
     }
 }
 
+impl<K: Into<Identifier>> Extend<(K, Module)> for StaticModuleResolver {
+    #[inline]
+    fn extend<T: IntoIterator<Item = (K, Module)>>(&mut self, iter: T) {
+        for (path, module) in iter {
+            self.insert(path, module);
+        }
+    }
+}
+
+impl<K: Into<Identifier>> FromIterator<(K, Module)> for StaticModuleResolver {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = (K, Module)>>(iter: T) -> Self {
+        let mut resolver = Self::new();
+        resolver.extend(iter);
+        resolver
+    }
+}
+
 impl AddAssign<Self> for StaticModuleResolver {
     #[inline(always)]
     fn add_assign(&mut self, rhs: Self) {
@@ -161,9 +451,12 @@ impl ModuleResolver for StaticModuleResolver {
         path: &str,
         pos: Position,
     ) -> RhaiResultOf<SharedModule> {
-        self.0
-            .get(path)
-            .cloned()
+        self.get(path)
             .ok_or_else(|| ERR::ErrorModuleNotFound(path.into(), pos).into())
     }
+
+    #[inline(always)]
+    fn can_resolve(&self, path: &str) -> bool {
+        self.contains_path(path)
+    }
 }
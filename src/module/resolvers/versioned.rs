@@ -0,0 +1,255 @@
+use crate::{
+    Engine, Identifier, Module, ModuleResolver, Position, RhaiResultOf, SharedModule, ERR,
+};
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+use std::{collections::BTreeMap, fmt};
+
+/// A `major.minor.patch` version number.
+///
+/// Missing components are taken as zero, so `1.2` is the same as `1.2.0`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ModuleVersion(u64, u64, u64);
+
+impl ModuleVersion {
+    /// Parse a version number, returning [`None`] if it is malformed.
+    #[must_use]
+    pub fn parse(text: &str) -> Option<Self> {
+        let (version, _) = Self::parse_partial(text)?;
+        Some(version)
+    }
+    /// Parse a version number, also returning the number of components given.
+    fn parse_partial(text: &str) -> Option<(Self, usize)> {
+        let mut parts = [0; 3];
+        let mut count = 0;
+
+        for part in text.trim().split('.') {
+            if count >= parts.len() {
+                return None;
+            }
+            parts[count] = part.parse().ok()?;
+            count += 1;
+        }
+
+        Some((Self(parts[0], parts[1], parts[2]), count))
+    }
+    /// Does this version satisfy a constraint?
+    ///
+    /// Supported constraints are:
+    ///
+    /// * `*` or empty -- any version
+    /// * `^1.2` or `1.2` -- compatible versions, i.e. `>=1.2.0` with the same left-most non-zero component
+    /// * `~1.2` -- `>=1.2.0` with the same major and minor version (or major version only if `~1`)
+    /// * `=1.2.3` -- exactly this version
+    /// * `>=1.2`, `>1.2`, `<=1.2`, `<1.2` -- comparisons
+    ///
+    /// Returns [`None`] if the constraint is malformed.
+    #[must_use]
+    pub fn satisfies(&self, constraint: &str) -> Option<bool> {
+        let constraint = constraint.trim();
+
+        if constraint.is_empty() || constraint == "*" {
+            return Some(true);
+        }
+
+        let ops = [">=", "<=", ">", "<", "=", "^", "~"];
+        let (op, rest) = ops
+            .iter()
+            .find_map(|&op| constraint.strip_prefix(op).map(|rest| (op, rest)))
+            .unwrap_or(("^", constraint));
+        let (min, count) = Self::parse_partial(rest)?;
+
+        Some(match op {
+            ">=" => *self >= min,
+            "<=" => *self <= min,
+            ">" => *self > min,
+            "<" => *self < min,
+            "=" => *self == min,
+            "~" if count <= 1 => *self >= min && self.0 == min.0,
+            "~" => *self >= min && self.0 == min.0 && self.1 == min.1,
+            _ if min.0 > 0 || count <= 1 => *self >= min && self.0 == min.0,
+            _ if min.1 > 0 || count <= 2 => *self >= min && self.0 == 0 && self.1 == min.1,
+            _ => *self == min,
+        })
+    }
+}
+
+impl fmt::Display for ModuleVersion {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.0, self.1, self.2)
+    }
+}
+
+/// A [module][Module] resolution service that serves multiple versions of the same
+/// [module][Module], selected by a version constraint in the import path.
+///
+/// An import path of the form `name@constraint` (e.g. `"math@^1.2"`) resolves to the highest
+/// registered version of `name` that satisfies the constraint.
+/// A path without a constraint resolves to the highest registered version.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+/// use rhai::{Engine, Module};
+/// use rhai::module_resolvers::VersionedModuleResolver;
+///
+/// let mut resolver = VersionedModuleResolver::new();
+///
+/// for (version, value) in [("1.2.0", 1_i64), ("1.4.1", 2), ("2.0.0", 3)] {
+///     let mut module = Module::new();
+///     module.set_var("value", value);
+///     resolver.add_version("math", version, module);
+/// }
+///
+/// let mut engine = Engine::new();
+/// engine.set_module_resolver(resolver);
+///
+/// assert_eq!(engine.eval::<i64>(r#"import "math@^1.2" as m; m::value"#)?, 2);
+/// assert_eq!(engine.eval::<i64>(r#"import "math" as m; m::value"#)?, 3);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct VersionedModuleResolver(BTreeMap<Identifier, BTreeMap<ModuleVersion, SharedModule>>);
+
+impl VersionedModuleResolver {
+    /// Create a new [`VersionedModuleResolver`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+    /// Add a version of a [module][Module] keyed by its name.
+    ///
+    /// An existing [module][Module] with the same name and version is replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the version number is malformed.
+    /// Use [`try_add_version`][VersionedModuleResolver::try_add_version] to handle it instead.
+    #[inline]
+    pub fn add_version(
+        &mut self,
+        name: impl Into<Identifier>,
+        version: &str,
+        module: Module,
+    ) -> &mut Self {
+        let name = name.into();
+
+        match self.try_add_version(name.clone(), version, module) {
+            Some(resolver) => resolver,
+            None => panic!("invalid version number for module '{name}': {version}"),
+        }
+    }
+    /// Add a version of a [module][Module] keyed by its name.
+    ///
+    /// Returns [`None`] (and the [module][Module] is not added) if the version number is malformed.
+    ///
+    /// An existing [module][Module] with the same name and version is replaced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::Module;
+    /// use rhai::module_resolvers::VersionedModuleResolver;
+    ///
+    /// let mut resolver = VersionedModuleResolver::new();
+    ///
+    /// assert!(resolver.try_add_version("math", "1.2.0", Module::new()).is_some());
+    /// assert!(resolver.try_add_version("math", "1.x", Module::new()).is_none());
+    /// ```
+    #[inline]
+    pub fn try_add_version(
+        &mut self,
+        name: impl Into<Identifier>,
+        version: &str,
+        mut module: Module,
+    ) -> Option<&mut Self> {
+        let name = name.into();
+        let version = ModuleVersion::parse(version)?;
+
+        if module.id().is_none() {
+            module.set_id(format!("{name}@{version}"));
+        }
+
+        module.build_index();
+        self.0
+            .entry(name)
+            .or_default()
+            .insert(version, module.into());
+        Some(self)
+    }
+    /// Get an iterator of all the registered versions of a [module][Module], in ascending order.
+    #[inline]
+    pub fn versions(&self, name: &str) -> impl Iterator<Item = ModuleVersion> + '_ {
+        self.0.get(name).into_iter().flat_map(|v| v.keys().copied())
+    }
+    /// Returns `true` if this [`VersionedModuleResolver`] contains no modules.
+    #[inline(always)]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl ModuleResolver for VersionedModuleResolver {
+    fn resolve(
+        &self,
+        _: &Engine,
+        _: Option<&str>,
+        path: &str,
+        pos: Position,
+    ) -> RhaiResultOf<SharedModule> {
+        let (name, constraint) = path.split_once('@').unwrap_or((path, ""));
+
+        let versions = self
+            .0
+            .get(name)
+            .ok_or_else(|| ERR::ErrorModuleNotFound(path.into(), pos))?;
+
+        let mut found = None;
+
+        for (version, module) in versions.iter().rev() {
+            match version.satisfies(constraint) {
+                Some(true) => {
+                    found = Some(module.clone());
+                    break;
+                }
+                Some(false) => (),
+                None => {
+                    let err = format!("Invalid version constraint: {constraint}");
+                    return Err(ERR::ErrorInModule(
+                        path.into(),
+                        ERR::ErrorRuntime(err.into(), pos).into(),
+                        pos,
+                    )
+                    .into());
+                }
+            }
+        }
+
+        found.ok_or_else(|| {
+            let available = versions
+                .keys()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            let err =
+                format!("No version of '{name}' satisfies {constraint} (available: {available})");
+            ERR::ErrorInModule(path.into(), ERR::ErrorRuntime(err.into(), pos).into(), pos).into()
+        })
+    }
+
+    #[inline]
+    fn can_resolve(&self, path: &str) -> bool {
+        let (name, constraint) = path.split_once('@').unwrap_or((path, ""));
+
+        self.0.get(name).map_or(false, |versions| {
+            versions
+                .keys()
+                .any(|v| v.satisfies(constraint) == Some(true))
+        })
+    }
+}
@@ -0,0 +1,489 @@
+//! Module containing helpers to write the contents of a [`Module`][crate::Module] as script source text.
+
+use crate::types::dynamic::Union;
+use crate::Dynamic;
+use std::fmt::Write;
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+#[cfg(not(feature = "no_function"))]
+use crate::ast::{
+    ASTFlags, BinaryExpr, Expr, FlowControl, FnCallExpr, ScriptFuncDef, Stmt, StmtBlock,
+};
+#[cfg(not(feature = "no_function"))]
+use crate::tokenizer::{is_valid_function_name, is_valid_identifier};
+
+/// Write a string literal.
+pub fn write_str(buf: &mut String, s: &str) {
+    buf.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            ch if ch.is_control() => {
+                let _ = write!(buf, "\\u{:04x}", ch as u32);
+            }
+            ch => buf.push(ch),
+        }
+    }
+    buf.push('"');
+}
+
+/// Write a value as a script literal.
+///
+/// Returns `false` if the value cannot be written as a literal.
+pub fn write_value(buf: &mut String, value: &Dynamic) -> bool {
+    match value.0 {
+        Union::Unit(..) => buf.push_str("()"),
+        Union::Bool(b, ..) => {
+            let _ = write!(buf, "{b}");
+        }
+        // `INT::MIN` has no literal form because its negation overflows
+        Union::Int(n, ..) if n == crate::INT::MIN => {
+            let _ = write!(buf, "({} - 1)", n + 1);
+        }
+        Union::Int(n, ..) => {
+            let _ = write!(buf, "{n}");
+        }
+        #[cfg(not(feature = "no_float"))]
+        Union::Float(f, ..) => {
+            if !f.is_finite() {
+                return false;
+            }
+            let text = format!("{:?}", *f);
+            if text.contains('.') {
+                buf.push_str(&text);
+            } else if let Some(pos) = text.find('e') {
+                buf.push_str(&text[..pos]);
+                buf.push_str(".0");
+                buf.push_str(&text[pos..]);
+            } else {
+                buf.push_str(&text);
+                buf.push_str(".0");
+            }
+        }
+        Union::Char(ch, ..) => match ch {
+            '\'' => buf.push_str("'\\''"),
+            '\\' => buf.push_str("'\\\\'"),
+            ch if ch.is_control() => {
+                let _ = write!(buf, "'\\u{:04x}'", ch as u32);
+            }
+            ch => {
+                let _ = write!(buf, "'{ch}'");
+            }
+        },
+        Union::Str(ref s, ..) => write_str(buf, s),
+        #[cfg(not(feature = "no_index"))]
+        Union::Array(ref a, ..) => {
+            buf.push('[');
+            for (i, item) in a.iter().enumerate() {
+                if i > 0 {
+                    buf.push_str(", ");
+                }
+                if !write_value(buf, item) {
+                    return false;
+                }
+            }
+            buf.push(']');
+        }
+        #[cfg(not(feature = "no_object"))]
+        Union::Map(ref m, ..) => {
+            buf.push_str("#{");
+            for (i, (k, item)) in m.iter().enumerate() {
+                if i > 0 {
+                    buf.push_str(", ");
+                }
+                write_str(buf, k);
+                buf.push_str(": ");
+                if !write_value(buf, item) {
+                    return false;
+                }
+            }
+            buf.push('}');
+        }
+        #[cfg(not(feature = "no_closure"))]
+        Union::Shared(..) => return write_value(buf, &value.flatten_clone()),
+        _ => return false,
+    }
+    true
+}
+
+/// Write a script-defined function as script source text.
+///
+/// Returns `false` if the function cannot be written back as script, for example because it is
+/// an anonymous function, or its body contains closures, `switch` statements or custom syntax.
+#[cfg(not(feature = "no_function"))]
+pub fn write_fn_def(buf: &mut String, fn_def: &ScriptFuncDef) -> bool {
+    if !is_valid_function_name(&fn_def.name) {
+        return false;
+    }
+
+    #[cfg(feature = "metadata")]
+    for comment in &fn_def.comments {
+        buf.push_str(comment);
+        buf.push('\n');
+    }
+
+    if fn_def.access.is_private() {
+        buf.push_str("private ");
+    }
+    buf.push_str("fn ");
+    #[cfg(not(feature = "no_object"))]
+    if let Some(ref this_type) = fn_def.this_type {
+        write_str(buf, this_type);
+        buf.push('.');
+    }
+    buf.push_str(&fn_def.name);
+    buf.push('(');
+    for (i, param) in fn_def.params.iter().enumerate() {
+        if i > 0 {
+            buf.push_str(", ");
+        }
+        buf.push_str(param);
+    }
+    buf.push_str(") ");
+
+    write_block(buf, &fn_def.body, 0).is_some()
+}
+
+/// Write indentation for the specified nesting level.
+#[cfg(not(feature = "no_function"))]
+fn write_indent(buf: &mut String, level: usize) {
+    for _ in 0..level {
+        buf.push_str("    ");
+    }
+}
+
+/// Write a statements block, one statement per line.
+#[cfg(not(feature = "no_function"))]
+fn write_block(buf: &mut String, block: &StmtBlock, level: usize) -> Option<()> {
+    if block.is_empty() {
+        buf.push_str("{}");
+        return Some(());
+    }
+
+    buf.push_str("{\n");
+
+    let last = block.len() - 1;
+
+    for (i, stmt) in block.iter().enumerate() {
+        write_indent(buf, level + 1);
+        write_stmt(buf, stmt, level + 1)?;
+        // Only separate statements, so that re-parsing yields the same statements
+        if stmt.is_noop() || (i < last && !stmt.is_self_terminated()) {
+            buf.push(';');
+        }
+        buf.push('\n');
+    }
+
+    write_indent(buf, level);
+    buf.push('}');
+    Some(())
+}
+
+/// Write a statement, without the terminating semicolon.
+#[cfg(not(feature = "no_function"))]
+fn write_stmt(buf: &mut String, stmt: &Stmt, level: usize) -> Option<()> {
+    match stmt {
+        Stmt::Noop(..) => (),
+        Stmt::If(x, ..) => {
+            buf.push_str("if ");
+            write_expr(buf, &x.expr, level)?;
+            buf.push(' ');
+            write_block(buf, &x.body, level)?;
+            if !x.branch.is_empty() {
+                buf.push_str(" else ");
+                write_block(buf, &x.branch, level)?;
+            }
+        }
+        Stmt::While(x, ..) if x.expr.is_unit() => {
+            buf.push_str("loop ");
+            write_block(buf, &x.body, level)?;
+        }
+        Stmt::While(x, ..) => {
+            buf.push_str("while ");
+            write_expr(buf, &x.expr, level)?;
+            buf.push(' ');
+            write_block(buf, &x.body, level)?;
+        }
+        Stmt::Do(x, options, ..) => {
+            buf.push_str("do ");
+            write_block(buf, &x.body, level)?;
+            if options.intersects(ASTFlags::NEGATED) {
+                buf.push_str(" until ");
+            } else {
+                buf.push_str(" while ");
+            }
+            write_expr(buf, &x.expr, level)?;
+        }
+        Stmt::For(x, ..) => {
+            let (var, counter, FlowControl { expr, body, .. }) = &**x;
+            buf.push_str("for ");
+            match counter {
+                Some(counter) => {
+                    let _ = write!(buf, "({}, {})", var.name, counter.name);
+                }
+                None => buf.push_str(&var.name),
+            }
+            buf.push_str(" in ");
+            write_expr(buf, expr, level)?;
+            buf.push(' ');
+            write_block(buf, body, level)?;
+        }
+        // `export` is not allowed inside functions
+        Stmt::Var(_, options, ..) if options.intersects(ASTFlags::EXPORTED) => return None,
+        Stmt::Var(x, options, ..) => {
+            let (var, expr, ..) = &**x;
+            if options.intersects(ASTFlags::CONSTANT) {
+                buf.push_str("const ");
+            } else {
+                buf.push_str("let ");
+            }
+            buf.push_str(&var.name);
+            buf.push_str(" = ");
+            write_expr(buf, expr, level)?;
+        }
+        Stmt::Assignment(x) => {
+            let (op_info, BinaryExpr { lhs, rhs }) = &**x;
+            write_expr(buf, lhs, level)?;
+            match op_info.get_op_assignment_info() {
+                Some((.., op_assign, _, _)) => {
+                    let _ = write!(buf, " {op_assign} ");
+                }
+                None => buf.push_str(" = "),
+            }
+            write_expr(buf, rhs, level)?;
+        }
+        Stmt::FnCall(x, ..) => write_fn_call(buf, x, level)?,
+        Stmt::Block(x) => write_block(buf, x, level)?,
+        Stmt::TryCatch(x, ..) => {
+            buf.push_str("try ");
+            write_block(buf, &x.body, level)?;
+            buf.push_str(" catch ");
+            if let Expr::Variable(v, ..) = &x.expr {
+                let _ = write!(buf, "({}) ", v.1);
+            }
+            write_block(buf, &x.branch, level)?;
+        }
+        Stmt::Expr(x) => write_expr(buf, x, level)?,
+        Stmt::BreakLoop(expr, options, ..) | Stmt::Return(expr, options, ..) => {
+            buf.push_str(match (stmt, options.intersects(ASTFlags::BREAK)) {
+                (Stmt::BreakLoop(..), true) => "break",
+                (Stmt::BreakLoop(..), false) => "continue",
+                (_, true) => "throw",
+                (_, false) => "return",
+            });
+            if let Some(expr) = expr {
+                buf.push(' ');
+                write_expr(buf, expr, level)?;
+            }
+        }
+        #[cfg(not(feature = "no_module"))]
+        Stmt::Import(x, ..) => {
+            let (expr, alias) = &**x;
+            buf.push_str("import ");
+            write_expr(buf, expr, level)?;
+            if !alias.name.is_empty() {
+                let _ = write!(buf, " as {}", alias.name);
+            }
+        }
+        Stmt::Switch(..) => return None,
+        #[cfg(not(feature = "no_module"))]
+        Stmt::Export(..) => return None,
+        #[cfg(not(feature = "no_closure"))]
+        Stmt::Share(..) => return None,
+    }
+
+    Some(())
+}
+
+/// Write a constant value inside an expression.
+#[cfg(not(feature = "no_function"))]
+fn write_constant(buf: &mut String, value: &Dynamic) -> Option<()> {
+    if let Union::FnPtr(ref f, ..) = value.0 {
+        // Closures and curried function pointers cannot be written back
+        if f.is_anonymous() || f.is_curried() {
+            return None;
+        }
+        buf.push_str("Fn(");
+        write_str(buf, f.fn_name());
+        buf.push(')');
+        return Some(());
+    }
+
+    let mut literal = String::new();
+
+    if !write_value(&mut literal, value) {
+        return None;
+    }
+
+    // Wrap negative numbers so that they do not combine with a preceding operator
+    if literal.starts_with('-') {
+        let _ = write!(buf, "({literal})");
+    } else {
+        buf.push_str(&literal);
+    }
+    Some(())
+}
+
+/// Write a binary operator expression, fully parenthesized.
+#[cfg(not(feature = "no_function"))]
+fn write_binary(buf: &mut String, x: &BinaryExpr, op: &str, level: usize) -> Option<()> {
+    buf.push('(');
+    write_expr(buf, &x.lhs, level)?;
+    let _ = write!(buf, " {op} ");
+    write_expr(buf, &x.rhs, level)?;
+    buf.push(')');
+    Some(())
+}
+
+/// Write a function call, method call or operator expression.
+#[cfg(not(feature = "no_function"))]
+fn write_fn_call(buf: &mut String, x: &FnCallExpr, level: usize) -> Option<()> {
+    // Operators are named by their symbols
+    if !is_valid_identifier(&x.name) {
+        match &*x.args {
+            [arg] => {
+                buf.push('(');
+                buf.push_str(&x.name);
+                write_expr(buf, arg, level)?;
+                buf.push(')');
+            }
+            [lhs, rhs] => {
+                buf.push('(');
+                write_expr(buf, lhs, level)?;
+                let _ = write!(buf, " {} ", x.name);
+                write_expr(buf, rhs, level)?;
+                buf.push(')');
+            }
+            _ => return None,
+        }
+        return Some(());
+    }
+
+    #[cfg(not(feature = "no_module"))]
+    if x.is_qualified() {
+        let _ = write!(buf, "{}{}", x.namespace, crate::engine::NAMESPACE_SEPARATOR);
+    }
+    buf.push_str(&x.name);
+    if x.capture_parent_scope {
+        buf.push('!');
+    }
+    buf.push('(');
+    for (i, arg) in x.args.iter().enumerate() {
+        if i > 0 {
+            buf.push_str(", ");
+        }
+        write_expr(buf, arg, level)?;
+    }
+    buf.push(')');
+    Some(())
+}
+
+/// Write the rest of a `.` or `[` ... `]` chain.
+///
+/// Unless the chain terminates here, the first element of the right-hand side is the property or
+/// index of this level, and the right-hand side continues the chain.
+#[cfg(not(feature = "no_function"))]
+fn write_chain(buf: &mut String, parent: &Expr, rhs: &Expr, level: usize) -> Option<()> {
+    // `?.` and `?[` are not written back
+    if parent.options().intersects(ASTFlags::NEGATED) {
+        return None;
+    }
+
+    let (item, next) = match rhs {
+        Expr::Dot(x, ..) | Expr::Index(x, ..) if !parent.options().intersects(ASTFlags::BREAK) => {
+            (&x.lhs, Some((rhs, &x.rhs)))
+        }
+        _ => (rhs, None),
+    };
+
+    if let Expr::Index(..) = parent {
+        buf.push('[');
+        write_expr(buf, item, level)?;
+        buf.push(']');
+    } else {
+        buf.push('.');
+        write_expr(buf, item, level)?;
+    }
+
+    match next {
+        Some((parent, rhs)) => write_chain(buf, parent, rhs, level),
+        None => Some(()),
+    }
+}
+
+/// Write an expression.
+#[cfg(not(feature = "no_function"))]
+fn write_expr(buf: &mut String, expr: &Expr, level: usize) -> Option<()> {
+    match expr {
+        Expr::DynamicConstant(x, ..) => write_constant(buf, x)?,
+        Expr::BoolConstant(x, ..) => write_constant(buf, &Dynamic::from_bool(*x))?,
+        Expr::IntegerConstant(x, ..) => write_constant(buf, &Dynamic::from_int(*x))?,
+        #[cfg(not(feature = "no_float"))]
+        Expr::FloatConstant(x, ..) => write_constant(buf, &Dynamic::from_float(**x))?,
+        Expr::CharConstant(x, ..) => write_constant(buf, &Dynamic::from_char(*x))?,
+        Expr::StringConstant(x, ..) => write_str(buf, x),
+        Expr::InterpolatedString(x, ..) => {
+            buf.push('`');
+            for expr in x {
+                buf.push_str("${");
+                write_expr(buf, expr, level)?;
+                buf.push('}');
+            }
+            buf.push('`');
+        }
+        Expr::Array(x, ..) => {
+            buf.push('[');
+            for (i, item) in x.iter().enumerate() {
+                if i > 0 {
+                    buf.push_str(", ");
+                }
+                write_expr(buf, item, level)?;
+            }
+            buf.push(']');
+        }
+        Expr::Map(x, ..) => {
+            buf.push_str("#{");
+            for (i, (key, value)) in x.0.iter().enumerate() {
+                if i > 0 {
+                    buf.push_str(", ");
+                }
+                if is_valid_function_name(&key.name) {
+                    buf.push_str(&key.name);
+                } else {
+                    write_str(buf, &key.name);
+                }
+                buf.push_str(": ");
+                write_expr(buf, value, level)?;
+            }
+            buf.push('}');
+        }
+        Expr::Unit(..) => buf.push_str("()"),
+        Expr::Variable(x, ..) => {
+            #[cfg(not(feature = "no_module"))]
+            if !x.2.is_empty() {
+                let _ = write!(buf, "{}{}", x.2, crate::engine::NAMESPACE_SEPARATOR);
+            }
+            buf.push_str(&x.1);
+        }
+        Expr::ThisPtr(..) => buf.push_str("this"),
+        Expr::Property(x, ..) => buf.push_str(&x.2),
+        Expr::MethodCall(x, ..) | Expr::FnCall(x, ..) => write_fn_call(buf, x, level)?,
+        Expr::Stmt(x) => write_block(buf, x, level)?,
+        Expr::Dot(x, ..) | Expr::Index(x, ..) => {
+            write_expr(buf, &x.lhs, level)?;
+            write_chain(buf, expr, &x.rhs, level)?;
+        }
+        Expr::And(x, ..) => write_binary(buf, x, "&&", level)?,
+        Expr::Or(x, ..) => write_binary(buf, x, "||", level)?,
+        Expr::Coalesce(x, ..) => write_binary(buf, x, "??", level)?,
+        #[cfg(not(feature = "no_custom_syntax"))]
+        Expr::Custom(..) => return None,
+    }
+
+    Some(())
+}
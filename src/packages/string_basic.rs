@@ -1,5 +1,6 @@
 use super::iter_basic::CharsStream;
 use crate::plugin::*;
+use crate::types::dynamic::Union;
 use crate::{def_package, FnPtr, ImmutableString, SmartString, INT, MAX_USIZE_INT};
 use std::any::TypeId;
 use std::fmt::{Binary, LowerHex, Octal, Write};
@@ -51,6 +52,12 @@ pub fn print_with_func(
         Ok(result) if result.is_string() => result.into_immutable_string().unwrap(),
         Ok(result) => ctx.engine().map_type_name(result.type_name()).into(),
         Err(_) => {
+            if fn_name == FUNC_TO_STRING {
+                if let Some(text) = display_with_module_fn(ctx, value) {
+                    return text.into();
+                }
+            }
+
             let mut buf = SmartString::new_const();
             match fn_name {
                 FUNC_TO_DEBUG => write!(&mut buf, "{value:?}").unwrap(),
@@ -61,6 +68,42 @@ pub fn print_with_func(
     }
 }
 
+/// Format a value using the display function registered for its type in a module, if any.
+///
+/// Modules in the global namespace are searched first, then imported modules (latest first), then
+/// static modules.
+fn display_with_module_fn(ctx: &NativeCallContext, value: &Dynamic) -> Option<String> {
+    let engine = ctx.engine();
+    let id = value.type_id();
+
+    let func = engine
+        .global_modules
+        .iter()
+        .find_map(|m| m.get_display_fn(id));
+
+    #[cfg(not(feature = "no_module"))]
+    let func = func
+        .or_else(|| ctx.iter_imports().find_map(|(_, m)| m.get_display_fn(id)))
+        .or_else(|| {
+            engine
+                .global_sub_modules
+                .values()
+                .find_map(|m| m.get_display_fn(id))
+        });
+
+    func.map(|func| func(value))
+}
+
+/// Is a value of a custom type, which has no standard formatting?
+fn is_custom_type(value: &Dynamic) -> bool {
+    match value.0 {
+        Union::Variant(..) => true,
+        #[cfg(not(feature = "no_closure"))]
+        Union::Shared(ref cell, ..) => is_custom_type(&crate::func::locked_read(cell).unwrap()),
+        _ => false,
+    }
+}
+
 #[export_module]
 mod print_debug_functions {
     /// Convert the value of the `item` into a string.
@@ -71,6 +114,13 @@ mod print_debug_functions {
     /// Convert the value of the `item` into a string.
     #[rhai_fn(name = "to_string", pure)]
     pub fn to_string_generic(ctx: NativeCallContext, item: &mut Dynamic) -> ImmutableString {
+        // Display functions in modules never override the standard formatting of standard types
+        if is_custom_type(item) {
+            if let Some(text) = display_with_module_fn(&ctx, item) {
+                return text.into();
+            }
+        }
+
         let mut buf = SmartString::new_const();
         write!(&mut buf, "{item}").unwrap();
         ctx.engine().map_type_name(&buf).into()
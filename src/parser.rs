@@ -442,6 +442,19 @@ fn parse_var_name(input: &mut TokenStream) -> ParseResult<(SmartString, Position
     }
 }
 
+/// Derive the default alias of an imported module from its path: the final component of the path,
+/// without any extension.
+///
+/// Returns [`None`] if the alias is not a valid identifier.
+#[cfg(not(feature = "no_module"))]
+#[must_use]
+fn default_import_alias(path: &str) -> Option<&str> {
+    let name = path.rsplit(|ch| ch == '/' || ch == '\\').next()?;
+    let name = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+
+    (is_valid_identifier(name) && !is_reserved_keyword_or_symbol(name).0).then_some(name)
+}
+
 /// Optimize the structure of a chained expression where the root expression is another chained expression.
 ///
 /// # Panics
@@ -2969,10 +2982,22 @@ impl Engine {
                 pos,
             }
         } else {
+            // import "path";
+            let alias = match expr {
+                Expr::StringConstant(ref path, pos)
+                    if settings.has_option(LangOptions::IMPLICIT_IMPORT_ALIAS) =>
+                {
+                    default_import_alias(path).map(|name| (name, pos))
+                }
+                _ => None,
+            };
+
             // import expr;
+            let (name, pos) = alias.unwrap_or(("", Position::NONE));
+
             Ident {
-                name: self.get_interned_string(""),
-                pos: Position::NONE,
+                name: self.get_interned_string(name),
+                pos,
             }
         };
 
@@ -3310,7 +3335,7 @@ impl Engine {
                             max_expr_depth: self.max_function_expr_depth(),
                         };
 
-                        let f = self.parse_fn(
+                        let (f, defaults) = self.parse_fn(
                             new_state,
                             new_settings,
                             access,
@@ -3318,23 +3343,31 @@ impl Engine {
                             comments,
                         )?;
 
-                        let hash = calc_fn_hash(None, &f.name, f.params.len());
+                        let overloads = if defaults.is_empty() {
+                            StaticVec::new_const()
+                        } else {
+                            self.make_default_param_fns(&f, defaults, pos)
+                        };
 
-                        #[cfg(not(feature = "no_object"))]
-                        let hash = f
-                            .this_type
-                            .as_ref()
-                            .map_or(hash, |typ| crate::calc_typed_method_hash(hash, typ));
+                        for f in std::iter::once(f).chain(overloads) {
+                            let hash = calc_fn_hash(None, &f.name, f.params.len());
 
-                        if state.lib.contains_key(&hash) {
-                            return Err(PERR::FnDuplicatedDefinition(
-                                f.name.to_string(),
-                                f.params.len(),
-                            )
-                            .into_err(pos));
-                        }
+                            #[cfg(not(feature = "no_object"))]
+                            let hash = f
+                                .this_type
+                                .as_ref()
+                                .map_or(hash, |typ| crate::calc_typed_method_hash(hash, typ));
+
+                            if state.lib.contains_key(&hash) {
+                                return Err(PERR::FnDuplicatedDefinition(
+                                    f.name.to_string(),
+                                    f.params.len(),
+                                )
+                                .into_err(pos));
+                            }
 
-                        state.lib.insert(hash, f.into());
+                            state.lib.insert(hash, f.into());
+                        }
 
                         Ok(Stmt::Noop(pos))
                     }
@@ -3517,7 +3550,7 @@ impl Engine {
         settings: ParseSettings,
         access: crate::FnAccess,
         #[cfg(feature = "metadata")] comments: impl IntoIterator<Item = crate::Identifier>,
-    ) -> ParseResult<ScriptFuncDef> {
+    ) -> ParseResult<(ScriptFuncDef, StaticVec<Expr>)> {
         let settings = settings.level_up()?;
 
         let (token, pos) = state.input.next().unwrap();
@@ -3580,6 +3613,7 @@ impl Engine {
         };
 
         let mut params = StaticVec::<(ImmutableString, _)>::new_const();
+        let mut defaults = StaticVec::<Expr>::new_const();
 
         if !no_params {
             let sep_err = format!("to separate the parameters of function '{name}'");
@@ -3595,6 +3629,29 @@ impl Engine {
                         }
 
                         let s = self.get_interned_string(*s);
+
+                        // param = default
+                        if match_token(state.input, &Token::Equals).0 {
+                            #[cfg(not(feature = "no_object"))]
+                            if this_type.is_some() {
+                                return Err(LexError::ImproperSymbol(
+                                    Token::Equals.literal_syntax().into(),
+                                    format!("Default parameter values are not supported for methods of a specific type: '{name}'"),
+                                )
+                                .into_err(pos));
+                            }
+
+                            // The default value is parsed before the parameter itself is pushed
+                            // so that it can only refer to the parameters before it.
+                            defaults.push(self.parse_expr(state, settings.level_up()?)?);
+                        } else if !defaults.is_empty() {
+                            return Err(PERR::MissingToken(
+                                Token::Equals.into(),
+                                format!("to give a default value to parameter '{s}' of function '{name}'"),
+                            )
+                            .into_err(pos));
+                        }
+
                         state.stack.push(s.clone(), ());
                         params.push((s, pos));
                     }
@@ -3629,16 +3686,70 @@ impl Engine {
         let mut params: FnArgsVec<_> = params.into_iter().map(|(p, ..)| p).collect();
         params.shrink_to_fit();
 
-        Ok(ScriptFuncDef {
+        let fn_def = ScriptFuncDef {
             name: self.get_interned_string(name),
             access,
             #[cfg(not(feature = "no_object"))]
             this_type,
             params,
+            pos: settings.pos,
             body,
             #[cfg(feature = "metadata")]
             comments: comments.into_iter().collect(),
-        })
+        };
+
+        Ok((fn_def, defaults))
+    }
+
+    /// Create overloads of a function definition for omitted trailing parameters with default values.
+    ///
+    /// Each overload declares the missing parameters as variables initialized to their default
+    /// values (evaluated at call time), followed by the body of the full function.
+    ///
+    /// The body is inlined (instead of calling the full function) so that `this` is bound in the
+    /// same way for method calls, and so that each call only counts once towards the limits on
+    /// function call levels.
+    #[cfg(not(feature = "no_function"))]
+    fn make_default_param_fns(
+        &self,
+        fn_def: &ScriptFuncDef,
+        defaults: StaticVec<Expr>,
+        pos: Position,
+    ) -> StaticVec<ScriptFuncDef> {
+        let num_params = fn_def.params.len();
+        let first_default = num_params - defaults.len();
+
+        (first_default..num_params)
+            .map(|num_supplied| {
+                let statements = fn_def.params[num_supplied..]
+                    .iter()
+                    .zip(defaults[num_supplied - first_default..].iter())
+                    .map(|(param, expr)| {
+                        let var_def = (
+                            Ident {
+                                name: param.clone(),
+                                pos,
+                            },
+                            expr.clone(),
+                            None,
+                        );
+                        Stmt::Var(var_def.into(), ASTFlags::empty(), pos)
+                    })
+                    .chain(fn_def.body.iter().cloned());
+
+                ScriptFuncDef {
+                    name: fn_def.name.clone(),
+                    access: fn_def.access,
+                    #[cfg(not(feature = "no_object"))]
+                    this_type: fn_def.this_type.clone(),
+                    params: fn_def.params[..num_supplied].iter().cloned().collect(),
+                    pos: fn_def.pos,
+                    body: StmtBlock::new_with_span(statements, fn_def.body.span()),
+                    #[cfg(feature = "metadata")]
+                    comments: <_>::default(),
+                }
+            })
+            .collect()
     }
 
     /// Creates a curried expression from a list of external variables
@@ -3794,6 +3905,7 @@ impl Engine {
             #[cfg(not(feature = "no_object"))]
             this_type: None,
             params,
+            pos: settings.pos,
             body: body.into(),
             #[cfg(not(feature = "no_function"))]
             #[cfg(feature = "metadata")]
@@ -3923,13 +4035,31 @@ impl Engine {
     }
 
     /// Run the parser on an input stream, returning an AST.
-    #[inline]
+    #[inline(always)]
     pub(crate) fn parse(
+        &self,
+        state: ParseState,
+        #[cfg(not(feature = "no_optimize"))] optimization_level: crate::OptimizationLevel,
+    ) -> ParseResult<AST> {
+        self.parse_with_options(
+            state,
+            self.options,
+            #[cfg(not(feature = "no_optimize"))]
+            optimization_level,
+        )
+    }
+
+    /// Run the parser on an input stream with [language options][LangOptions] replacing those of
+    /// the [`Engine`], returning an AST.
+    #[inline]
+    pub(crate) fn parse_with_options(
         &self,
         mut state: ParseState,
+        options: LangOptions,
         #[cfg(not(feature = "no_optimize"))] optimization_level: crate::OptimizationLevel,
     ) -> ParseResult<AST> {
-        let (statements, _lib) = self.parse_global_level(&mut state, |_| {})?;
+        let (statements, _lib) =
+            self.parse_global_level(&mut state, |settings| settings.options = options)?;
 
         #[cfg(not(feature = "no_optimize"))]
         return Ok(self.optimize_into_ast(
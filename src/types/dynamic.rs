@@ -1566,6 +1566,41 @@ impl Dynamic {
             _ => Err(self),
         }
     }
+    /// Convert the [`Dynamic`] value into specific type, handing back the original value if the
+    /// types mismatch.
+    ///
+    /// This allows trying several target types in turn without cloning the value first.
+    ///
+    /// Casting to a [`Dynamic`] simply returns itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns the original [`Dynamic`] value if types mismatch.
+    ///
+    /// # Shared Value
+    ///
+    /// If the [`Dynamic`] is a _shared_ value, it is first flattened: it becomes the shared value if
+    /// there are no outstanding references, or a cloned copy otherwise.  The value handed back on
+    /// failure is therefore no longer shared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::{Dynamic, ImmutableString, INT};
+    ///
+    /// let x = Dynamic::from("hello");
+    ///
+    /// let x = match x.try_cast_or_self::<INT>() {
+    ///     Ok(_) => unreachable!(),
+    ///     Err(x) => x,
+    /// };
+    ///
+    /// assert_eq!(x.try_cast_or_self::<ImmutableString>().unwrap(), "hello");
+    /// ```
+    #[inline(always)]
+    pub fn try_cast_or_self<T: Any>(self) -> Result<T, Self> {
+        self.try_cast_raw()
+    }
     /// Convert the [`Dynamic`] value into a specific type.
     ///
     /// Casting to a [`Dynamic`] just returns as is.
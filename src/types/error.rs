@@ -49,6 +49,16 @@ pub enum EvalAltResult {
     ErrorFunctionNotFound(String, Position),
     /// Usage of an unknown [module][crate::Module]. Wrapped value is the [module][crate::Module] name.
     ErrorModuleNotFound(String, Position),
+    /// The script file of a [module][crate::Module] cannot be found.
+    /// Wrapped values are the [module][crate::Module] path and the full file paths searched.
+    ///
+    /// This is raised instead of [`ErrorModuleNotFound`][EvalAltResult::ErrorModuleNotFound] by
+    /// [module resolvers][crate::ModuleResolver] that load script files, and is treated in the
+    /// same way when falling back to other [module resolvers][crate::ModuleResolver].
+    ErrorModuleFileNotFound(String, Vec<String>, Position),
+    /// A [module][crate::Module] imports itself, directly or indirectly, while it is being
+    /// resolved. Wrapped value is the [module][crate::Module] path.
+    ErrorCircularImport(String, Position),
 
     /// An error has occurred inside a called function.
     /// Wrapped values are the function name, function source, and the interior error.
@@ -99,6 +109,8 @@ pub enum EvalAltResult {
     ErrorTooManyVariables(Position),
     /// [Modules][crate::Module] over maximum limit.
     ErrorTooManyModules(Position),
+    /// Number of script-defined function calls over maximum limit.
+    ErrorTooManyFunctionCalls(Position),
     /// Call stack over maximum limit.
     ErrorStackOverflow(Position),
     /// Data value over maximum size limit. Wrapped value is the type name.
@@ -128,7 +140,19 @@ pub enum EvalAltResult {
     Exit(Dynamic, Position),
 }
 
-impl Error for EvalAltResult {}
+impl Error for EvalAltResult {
+    /// The interior error of [`ErrorInFunctionCall`][EvalAltResult::ErrorInFunctionCall] or
+    /// [`ErrorInModule`][EvalAltResult::ErrorInModule].
+    ///
+    /// Following the chain of sources unwinds a nested failure, e.g. an error inside a
+    /// [module][crate::Module] that is imported by another [module][crate::Module].
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::ErrorInFunctionCall(.., err, _) | Self::ErrorInModule(_, err, _) => Some(&**err),
+            _ => None,
+        }
+    }
+}
 
 impl fmt::Display for EvalAltResult {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -162,6 +186,10 @@ impl fmt::Display for EvalAltResult {
             Self::ErrorIndexNotFound(s, ..) => write!(f, "Invalid index: {s}")?,
             Self::ErrorFunctionNotFound(s, ..) => write!(f, "Function not found: {s}")?,
             Self::ErrorModuleNotFound(s, ..) => write!(f, "Module not found: {s}")?,
+            Self::ErrorModuleFileNotFound(s, paths, ..) => {
+                write!(f, "Module not found: {s} (searched: {})", paths.join(", "))?
+            }
+            Self::ErrorCircularImport(s, ..) => write!(f, "Circular import of module: {s}")?,
             Self::ErrorDataRace(s, ..) => write!(f, "Data race detected on variable '{s}'")?,
 
             Self::ErrorDotExpr(s, ..) if s.is_empty() => f.write_str("Malformed dot expression")?,
@@ -173,6 +201,7 @@ impl fmt::Display for EvalAltResult {
             Self::ErrorTooManyOperations(..) => f.write_str("Too many operations")?,
             Self::ErrorTooManyVariables(..) => f.write_str("Too many variables defined")?,
             Self::ErrorTooManyModules(..) => f.write_str("Too many modules imported")?,
+            Self::ErrorTooManyFunctionCalls(..) => f.write_str("Too many function calls")?,
             Self::ErrorStackOverflow(..) => f.write_str("Stack overflow")?,
             Self::ErrorTerminated(..) => f.write_str("Script terminated")?,
 
@@ -326,6 +355,8 @@ impl EvalAltResult {
             | Self::ErrorPropertyNotFound(..)
             | Self::ErrorIndexNotFound(..)
             | Self::ErrorModuleNotFound(..)
+            | Self::ErrorModuleFileNotFound(..)
+            | Self::ErrorCircularImport(..)
             | Self::ErrorDataRace(..)
             | Self::ErrorNonPureMethodCallOnConstant(..)
             | Self::ErrorAssignmentToConstant(..)
@@ -343,6 +374,7 @@ impl EvalAltResult {
             Self::ErrorTooManyOperations(..)
             | Self::ErrorTooManyVariables(..)
             | Self::ErrorTooManyModules(..)
+            | Self::ErrorTooManyFunctionCalls(..)
             | Self::ErrorStackOverflow(..)
             | Self::ErrorDataTooLarge(..)
             | Self::ErrorTerminated(..) => false,
@@ -363,6 +395,7 @@ impl EvalAltResult {
                 | Self::ErrorTooManyOperations(..)
                 | Self::ErrorTooManyVariables(..)
                 | Self::ErrorTooManyModules(..)
+                | Self::ErrorTooManyFunctionCalls(..)
                 | Self::ErrorStackOverflow(..)
                 | Self::ErrorDataTooLarge(..)
                 | Self::ErrorTerminated(..)
@@ -393,6 +426,7 @@ impl EvalAltResult {
             | Self::ErrorTooManyOperations(..)
             | Self::ErrorTooManyVariables(..)
             | Self::ErrorTooManyModules(..)
+            | Self::ErrorTooManyFunctionCalls(..)
             | Self::ErrorStackOverflow(..)
             | Self::ErrorRuntime(..) => (),
 
@@ -424,9 +458,21 @@ impl EvalAltResult {
             Self::ErrorIndexNotFound(v, ..) => {
                 map.insert("index".into(), v.clone());
             }
-            Self::ErrorInModule(m, ..) | Self::ErrorModuleNotFound(m, ..) => {
+            Self::ErrorInModule(m, ..)
+            | Self::ErrorModuleNotFound(m, ..)
+            | Self::ErrorCircularImport(m, ..) => {
                 map.insert("module".into(), m.into());
             }
+            Self::ErrorModuleFileNotFound(m, paths, ..) => {
+                map.insert("module".into(), m.into());
+                map.insert(
+                    "paths".into(),
+                    #[cfg(not(feature = "no_index"))]
+                    Dynamic::from_array(paths.iter().map(Into::into).collect()),
+                    #[cfg(feature = "no_index")]
+                    paths.join(", ").into(),
+                );
+            }
             Self::ErrorDotExpr(p, ..) => {
                 map.insert("property".into(), p.into());
             }
@@ -489,6 +535,8 @@ impl EvalAltResult {
             | Self::ErrorPropertyNotFound(.., pos)
             | Self::ErrorIndexNotFound(.., pos)
             | Self::ErrorModuleNotFound(.., pos)
+            | Self::ErrorModuleFileNotFound(.., pos)
+            | Self::ErrorCircularImport(.., pos)
             | Self::ErrorDataRace(.., pos)
             | Self::ErrorNonPureMethodCallOnConstant(.., pos)
             | Self::ErrorAssignmentToConstant(.., pos)
@@ -498,6 +546,7 @@ impl EvalAltResult {
             | Self::ErrorTooManyOperations(pos)
             | Self::ErrorTooManyVariables(pos)
             | Self::ErrorTooManyModules(pos)
+            | Self::ErrorTooManyFunctionCalls(pos)
             | Self::ErrorStackOverflow(pos)
             | Self::ErrorDataTooLarge(.., pos)
             | Self::ErrorTerminated(.., pos)
@@ -551,6 +600,8 @@ impl EvalAltResult {
             | Self::ErrorPropertyNotFound(.., pos)
             | Self::ErrorIndexNotFound(.., pos)
             | Self::ErrorModuleNotFound(.., pos)
+            | Self::ErrorModuleFileNotFound(.., pos)
+            | Self::ErrorCircularImport(.., pos)
             | Self::ErrorDataRace(.., pos)
             | Self::ErrorNonPureMethodCallOnConstant(.., pos)
             | Self::ErrorAssignmentToConstant(.., pos)
@@ -560,6 +611,7 @@ impl EvalAltResult {
             | Self::ErrorTooManyOperations(pos)
             | Self::ErrorTooManyVariables(pos)
             | Self::ErrorTooManyModules(pos)
+            | Self::ErrorTooManyFunctionCalls(pos)
             | Self::ErrorStackOverflow(pos)
             | Self::ErrorDataTooLarge(.., pos)
             | Self::ErrorTerminated(.., pos)
@@ -571,6 +623,38 @@ impl EvalAltResult {
         }
         self
     }
+    /// Consume the current [`EvalAltResult`] and return a new one with the specified [`Position`],
+    /// overriding the current position.
+    ///
+    /// This is useful for stamping positions onto errors in native Rust functions and custom
+    /// [module resolvers][crate::ModuleResolver], e.g. `.map_err(|err| err.with_position(pos))`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::{Engine, EvalAltResult, NativeCallContext, Position, INT};
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine.register_fn("parse_int", |ctx: NativeCallContext, s: &str| {
+    ///     s.parse::<INT>().map_err(|err| {
+    ///         let err: Box<EvalAltResult> = err.to_string().into();
+    ///         err.with_position(ctx.position())
+    ///     })
+    /// });
+    ///
+    /// let err = engine.eval::<INT>(r#"let x = 1; parse_int("oops")"#).unwrap_err();
+    ///
+    /// # #[cfg(not(feature = "no_position"))]
+    /// assert_eq!(err.position(), Position::new(1, 12));
+    /// ```
+    #[cold]
+    #[inline(never)]
+    #[must_use]
+    pub fn with_position(mut self: Box<Self>, new_position: Position) -> Box<Self> {
+        self.set_position(new_position);
+        self
+    }
     /// Consume the current [`EvalAltResult`] and return a new one with the specified [`Position`]
     /// if the current position is [`Position::NONE`].
     #[cold]
@@ -583,3 +667,47 @@ impl EvalAltResult {
         self
     }
 }
+
+/// Fail with a runtime error carrying a value, which a script can inspect in a `catch` block.
+///
+/// This is a shortcut for returning
+/// [`ErrorRuntime`][EvalAltResult::ErrorRuntime]`(value, pos)` from a native Rust function.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+/// use rhai::{fail_with, Engine, Map, Position};
+///
+/// let mut engine = Engine::new();
+///
+/// engine.register_fn("withdraw", |amount: i64| -> Result<i64, Box<rhai::EvalAltResult>> {
+///     if amount > 100 {
+///         let mut err = Map::new();
+///         err.insert("code".into(), 402_i64.into());
+///         err.insert("needed".into(), (amount - 100).into());
+///         return fail_with(err, Position::NONE);
+///     }
+///     Ok(100 - amount)
+/// });
+///
+/// let result = engine.eval::<i64>("
+///     try {
+///         withdraw(142)
+///     } catch (err) {
+///         err.needed
+///     }
+/// ")?;
+///
+/// assert_eq!(result, 42);
+/// # Ok(())
+/// # }
+/// ```
+#[cold]
+#[inline(never)]
+pub fn fail_with<T>(
+    value: impl crate::types::dynamic::Variant + Clone,
+    pos: Position,
+) -> Result<T, Box<EvalAltResult>> {
+    Err(EvalAltResult::ErrorRuntime(Dynamic::from(value), pos).into())
+}
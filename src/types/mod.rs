@@ -20,7 +20,7 @@ pub use custom_types::{CustomTypeInfo, CustomTypesCollection};
 pub use dynamic::Dynamic;
 #[cfg(not(feature = "no_time"))]
 pub use dynamic::Instant;
-pub use error::EvalAltResult;
+pub use error::{fail_with, EvalAltResult};
 #[cfg(not(feature = "no_float"))]
 pub use float::FloatWrapper;
 pub use fn_ptr::FnPtr;
@@ -34,5 +34,5 @@ pub use position::{Position, Span};
 #[cfg(feature = "no_position")]
 pub use position_none::{Position, Span};
 
-pub use scope::Scope;
+pub use scope::{Scope, ScopeSnapshot};
 pub use variant::Variant;
@@ -111,6 +111,12 @@ impl Clone for Scope<'_> {
     }
 }
 
+/// A snapshot of the entries in a [`Scope`], taken by [`Scope::snapshot`].
+///
+/// Restore it with [`Scope::restore`].
+#[derive(Debug, Clone, Default)]
+pub struct ScopeSnapshot(Scope<'static>);
+
 impl IntoIterator for Scope<'_> {
     type Item = (String, Dynamic, Vec<ImmutableString>);
     type IntoIter = Box<dyn Iterator<Item = Self::Item>>;
@@ -453,6 +459,66 @@ impl Scope<'_> {
         self.aliases.truncate(size);
         self
     }
+    /// Take a snapshot of all the entries (names, values, constness and aliases) in the [`Scope`],
+    /// to be rolled back to later via [`restore`][Scope::restore].
+    ///
+    /// Both taking and restoring a snapshot clone all the entries.
+    ///
+    /// # Shared Values
+    ///
+    /// Values are cloned.  A shared value (e.g. a variable captured by a closure) is cloned as
+    /// a reference to the same shared data, so changes to the data (as opposed to the variable)
+    /// are not rolled back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Scope};
+    ///
+    /// let engine = Engine::new();
+    ///
+    /// let mut my_scope = Scope::new();
+    /// my_scope.push("x", 40_i64);
+    ///
+    /// let snapshot = my_scope.snapshot();
+    ///
+    /// engine.run_with_scope(&mut my_scope, "x = 0; let y = 1;")?;
+    /// assert_eq!(my_scope.get_value::<i64>("x").expect("x should exist"), 0);
+    /// assert!(my_scope.contains("y"));
+    ///
+    /// my_scope.restore(&snapshot);
+    /// assert_eq!(my_scope.get_value::<i64>("x").expect("x should exist"), 40);
+    /// assert!(!my_scope.contains("y"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn snapshot(&self) -> ScopeSnapshot {
+        let scope = self.clone();
+
+        ScopeSnapshot(Scope {
+            values: scope.values,
+            names: scope.names,
+            aliases: scope.aliases,
+            dummy: PhantomData,
+        })
+    }
+    /// Restore all the entries in the [`Scope`] from a snapshot taken by
+    /// [`snapshot`][Scope::snapshot].
+    ///
+    /// All current entries are replaced.  The snapshot is not consumed, so it can be restored
+    /// many times.
+    #[inline]
+    pub fn restore(&mut self, snapshot: &ScopeSnapshot) -> &mut Self {
+        let scope = snapshot.0.clone();
+
+        self.values = scope.values;
+        self.names = scope.names;
+        self.aliases = scope.aliases;
+        self
+    }
     /// Does the [`Scope`] contain the entry?
     ///
     /// # Example
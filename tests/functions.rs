@@ -574,3 +574,56 @@ fn test_functions_max() {
         ParseErrorType::TooManyFunctions
     ))
 }
+
+#[test]
+fn test_functions_default_params() {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine
+            .eval::<String>(
+                r#"
+                    fn greet(name, greeting = "Hello") { `${greeting}, ${name}!` }
+                    greet("Bob") + " " + greet("Alice", "Hi")
+                "#
+            )
+            .unwrap(),
+        "Hello, Bob! Hi, Alice!"
+    );
+    assert_eq!(engine.eval::<INT>("fn add(x, y = x * 2, z = y + 1) { x + y + z } add(1)").unwrap(), 6);
+    assert_eq!(engine.eval::<INT>("fn add(x, y = x * 2, z = y + 1) { x + y + z } add(1, 10)").unwrap(), 22);
+    assert_eq!(engine.eval::<INT>("fn add(x, y = x * 2, z = y + 1) { x + y + z } add(1, 10, 100)").unwrap(), 111);
+    assert_eq!(engine.eval::<INT>("fn count(x = 42) { x } count() + count(1)").unwrap(), 43);
+
+    #[cfg(not(feature = "no_object"))]
+    {
+        assert_eq!(engine.eval::<INT>("fn inc(by = 1) { this += by } let x = 41; x.inc(); x").unwrap(), 42);
+        assert_eq!(engine.eval::<INT>("fn inc(by = 1) { this += by } let x = 40; x.inc(2); x").unwrap(), 42);
+        assert_eq!(engine.eval::<INT>("fn int.foo(a = 1, b = a + 1) { this * 100 + a * 10 + b } let x = 4; x.foo() + x.foo(2)").unwrap(), 412 + 423);
+        assert!(engine.eval::<INT>(r#"fn int.foo(a = 1) { this + a } let x = "hello"; x.foo()"#).is_err());
+    }
+
+    #[cfg(not(feature = "unchecked"))]
+    {
+        let mut engine = Engine::new();
+        engine.set_max_script_fn_calls(1);
+
+        assert_eq!(engine.eval::<INT>("fn count(x = 42) { x } count()").unwrap(), 42);
+        assert!(matches!(*engine.run("fn count(x = 42) { x } count(); count()").unwrap_err(), EvalAltResult::ErrorTooManyFunctionCalls(..)));
+    }
+
+    assert!(matches!(*engine.compile("fn foo(x = 1, y) { x + y }").unwrap_err().0, ParseErrorType::MissingToken(..)));
+    assert!(matches!(*engine.compile("fn foo(x, y = 1) { x + y } fn foo(x) { x }").unwrap_err().0, ParseErrorType::FnDuplicatedDefinition(..)));
+
+    #[cfg(not(feature = "no_module"))]
+    {
+        let ast = engine.compile(r#"fn greet(name, greeting = "Hello") { `${greeting}, ${name}!` }"#).unwrap();
+        let module = Module::eval_ast_as_new(rhai::Scope::new(), &ast, &engine).unwrap();
+
+        let mut engine = Engine::new();
+        engine.register_static_module("m", module.into());
+
+        assert_eq!(engine.eval::<String>(r#"m::greet("Bob")"#).unwrap(), "Hello, Bob!");
+        assert_eq!(engine.eval::<String>(r#"m::greet("Bob", "Bye")"#).unwrap(), "Bye, Bob!");
+    }
+}
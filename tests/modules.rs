@@ -585,3 +585,2199 @@ fn test_module_dynamic() {
 
     assert_eq!(engine.eval::<INT>(r#"import "test" as test; test::test("test", 38);"#).unwrap(), 42);
 }
+
+#[test]
+fn test_module_visitor() {
+    use rhai::{FuncMetadata, ModuleVisitor};
+
+    #[derive(Default)]
+    struct Collector {
+        vars: Vec<String>,
+        fns: Vec<String>,
+        depth: usize,
+        max_depth: usize,
+    }
+
+    impl ModuleVisitor for Collector {
+        fn visit_var(&mut self, path: &[&str], name: &str, _: &Dynamic) {
+            self.vars.push(path.iter().chain(Some(&name)).copied().collect::<Vec<_>>().join("::"));
+        }
+        fn visit_fn(&mut self, path: &[&str], metadata: &FuncMetadata) {
+            self.fns.push(path.iter().chain(Some(&metadata.name.as_str())).copied().collect::<Vec<_>>().join("::"));
+        }
+        fn enter_sub_module(&mut self, _: &str) {
+            self.depth += 1;
+            self.max_depth = self.max_depth.max(self.depth);
+        }
+        fn exit_sub_module(&mut self) {
+            self.depth -= 1;
+        }
+    }
+
+    let mut sub_module2 = Module::new();
+    sub_module2.set_var("answer", 41 as INT);
+    sub_module2.set_native_fn("inc", |x: INT| Ok(x + 1));
+
+    let mut sub_module = Module::new();
+    sub_module.set_sub_module("universe", sub_module2);
+
+    let mut module = Module::new();
+    module.set_var("MYSTIC_NUMBER", 42 as INT);
+    module.set_sub_module("life", sub_module);
+
+    let mut collector = Collector::default();
+    module.accept(&mut collector);
+
+    assert_eq!(collector.vars, ["MYSTIC_NUMBER", "life::universe::answer"]);
+    assert_eq!(collector.fns, ["life::universe::inc"]);
+    assert_eq!(collector.depth, 0);
+    assert_eq!(collector.max_depth, 2);
+}
+
+#[test]
+fn test_module_fn_mut_both() {
+    let mut module = Module::new();
+    module.set_native_fn_mut_both("swap", |x: &mut INT, y: &mut INT| {
+        std::mem::swap(x, y);
+        Ok(())
+    });
+    module.set_native_fn_mut_both("transfer", |x: &mut INT, y: &mut INT| {
+        *x += *y;
+        *y = 0;
+        Ok(*x)
+    });
+
+    let mut engine = Engine::new();
+    engine.register_global_module(module.into());
+
+    assert_eq!(engine.eval::<INT>("let x = 1; let y = 2; swap(x, y); x").unwrap(), 2);
+    assert_eq!(engine.eval::<INT>("let x = 1; let y = 2; x.swap(y); x").unwrap(), 2);
+    assert_eq!(engine.eval::<INT>("let x = 40; transfer(x, 2)").unwrap(), 42);
+}
+
+#[cfg(not(feature = "no_closure"))]
+#[test]
+fn test_module_fn_mut_both_shared() {
+    let mut module = Module::new();
+    module.set_native_fn_mut_both("swap", |x: &mut INT, y: &mut INT| {
+        std::mem::swap(x, y);
+        Ok(())
+    });
+
+    let mut engine = Engine::new();
+    engine.register_global_module(module.into());
+
+    // Pass the same shared value as both arguments
+    engine.register_fn("swap_self", |ctx: rhai::NativeCallContext, x: INT| -> Result<(), Box<EvalAltResult>> {
+        let mut a = Dynamic::from(x).into_shared();
+        let mut b = a.clone();
+        ctx.call_native_fn_raw("swap", true, &mut [&mut a, &mut b]).map(|_| ())
+    });
+
+    assert!(matches!(*engine.run("swap_self(1)").unwrap_err(), EvalAltResult::ErrorDataRace(ref arg, ..) if arg == "argument #2 of function 'swap'"));
+}
+
+#[test]
+fn test_module_find_fn() {
+    let mut module = Module::new();
+    let hash_start = module.set_native_fn("on_start", || Ok(()));
+    let hash_event = module.set_native_fn("on_event", |x: INT, y: INT| Ok(x + y));
+    module.set_native_fn("calc", |x: INT, y: INT| Ok(x * y));
+
+    let mut found = module.find_fn(|name, _| name.starts_with("on_"));
+    found.sort();
+    let mut expected = vec![hash_start, hash_event];
+    expected.sort();
+    assert_eq!(found, expected);
+
+    assert_eq!(module.find_fn(|_, num_params| num_params == 2).len(), 2);
+    assert!(module.find_fn(|name, _| name == "unknown").is_empty());
+
+    let names = module.find_fn_metadata(|_, num_params| num_params == 0).map(|f| f.name.to_string()).collect::<Vec<_>>();
+    assert_eq!(names, ["on_start"]);
+}
+
+#[test]
+fn test_module_same_api() {
+    let make = |answer: INT| {
+        let mut sub_module = Module::new();
+        sub_module.set_var("answer", answer);
+        sub_module.set_native_fn("inc", |x: INT| Ok(x + 1));
+
+        let mut module = Module::new();
+        module.set_var("MYSTIC_NUMBER", answer);
+        module.set_native_fn("calc", |x: INT, y: INT| Ok(x + y));
+        module.set_sub_module("life", sub_module);
+        module
+    };
+
+    let module1 = make(42);
+    let mut module2 = make(0);
+    assert!(module1.same_api(&module2));
+
+    module2.set_native_fn("calc", |x: INT| Ok(x));
+    assert!(!module1.same_api(&module2));
+
+    let mut module3 = make(0);
+    module3.set_sub_module("life", Module::new());
+    assert!(!module1.same_api(&module3));
+
+    let mut module4 = make(0);
+    module4.set_var("extra", 1 as INT);
+    assert!(!module1.same_api(&module4));
+}
+
+#[test]
+fn test_module_raw_fn_name() {
+    let mut module = Module::new();
+
+    for name in ["double", "triple"] {
+        module.set_raw_fn(name, [std::any::TypeId::of::<INT>()], |ctx, args| {
+            let x = args[0].as_int().unwrap();
+            Ok(if ctx.fn_name() == "double" { x * 2 } else { x * 3 })
+        });
+    }
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.into());
+
+    assert_eq!(engine.eval::<INT>("m::double(21)").unwrap(), 42);
+    assert_eq!(engine.eval::<INT>("m::triple(14)").unwrap(), 42);
+}
+
+#[test]
+fn test_module_raw_fn_with_return_type() {
+    let mut module = Module::new();
+
+    module.set_raw_fn_with_return_type::<INT>("get", [std::any::TypeId::of::<bool>()], |_, args| Ok(if args[0].as_bool().unwrap() { Dynamic::from(42 as INT) } else { "oops".into() }));
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.into());
+
+    assert_eq!(engine.eval::<INT>("m::get(true)").unwrap(), 42);
+    assert!(matches!(*engine.run("m::get(false)").unwrap_err(), EvalAltResult::ErrorMismatchOutputType(..)));
+}
+
+#[cfg(not(feature = "no_object"))]
+#[test]
+fn test_module_dynamic_getter() {
+    #[derive(Clone)]
+    struct Bag(std::collections::BTreeMap<String, INT>);
+
+    let mut module = Module::new();
+    module.set_dynamic_getter_fn(|bag: &mut Bag, name| Ok(bag.0.get(name).map(|&v| Dynamic::from(v))));
+    module.set_getter_fn("count", |bag: &mut Bag| Ok(bag.0.len() as INT));
+    module.set_native_fn("bag", || Ok(Bag([("a".to_string(), 1 as INT), ("b".to_string(), 41 as INT)].into())));
+
+    let mut engine = Engine::new();
+    engine.register_global_module(module.into());
+
+    assert_eq!(engine.eval::<INT>("let x = bag(); x.a + x.b").unwrap(), 42);
+    assert_eq!(engine.eval::<INT>("let x = bag(); x.count").unwrap(), 2);
+    assert_eq!(engine.eval::<INT>(r#"let x = bag(); x["b"]"#).unwrap(), 41);
+    assert!(matches!(*engine.run("let x = bag(); x.c").unwrap_err(), EvalAltResult::ErrorPropertyNotFound(p, ..) if p == "c"));
+}
+
+#[test]
+fn test_module_replace_fn() {
+    let mut module = Module::new();
+    let hash = module.set_native_fn("calc", |x: INT, y: INT| Ok(x + y));
+    let hash_method = module.set_native_fn("inc", |x: &mut INT| {
+        *x += 1;
+        Ok(())
+    });
+
+    assert!(module.replace_fn(hash, |ctx, args| Ok(format!("{}({}, {})", ctx.fn_name(), args[0], args[1]).into())));
+    assert!(module.replace_fn(hash_method, |_, args| {
+        *args[0] = (args[0].as_int().unwrap() + 10).into();
+        Ok(Dynamic::UNIT)
+    }));
+    assert!(!module.replace_fn(0, |_, _| Ok(Dynamic::UNIT)));
+    assert_eq!(module.find_fn(|name, num_params| name == "calc" && num_params == 2), [hash]);
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.into());
+
+    assert_eq!(engine.eval::<String>("m::calc(1, 2)").unwrap(), "calc(1, 2)");
+    assert_eq!(engine.eval::<INT>("let x = 1; m::inc(x); x").unwrap(), 11);
+}
+
+#[test]
+fn test_module_ast_imports() {
+    let engine = Engine::new();
+
+    let ast = engine
+        .compile(
+            r#"
+                import "a" as a;
+                fn foo() { import "b"; }
+                if true { import "c" + "d" as cd; }
+            "#,
+        )
+        .unwrap();
+
+    let imports: Vec<_> = ast.imports().into_iter().map(|(path, alias, _)| (path, alias)).collect();
+
+    assert_eq!(imports.len(), 3);
+    assert!(imports.contains(&(Some("a".to_string()), Some("a".to_string()))));
+    assert!(imports.contains(&(Some("b".to_string()), None)));
+    assert!(imports.iter().any(|(_, alias)| alias.as_deref() == Some("cd")));
+}
+
+#[test]
+fn test_module_const_table() {
+    let mut module = Module::new();
+    module.set_const_table(&[("NORTH", 0 as INT), ("EAST", 90), ("SOUTH", 180), ("WEST", 270)]);
+    module.set_const_table(&[("ON", true), ("OFF", false)]);
+
+    let mut engine = Engine::new();
+    engine.register_static_module("dir", module.into());
+
+    assert_eq!(engine.eval::<INT>("dir::EAST + dir::SOUTH").unwrap(), 270);
+    assert_eq!(engine.eval::<String>("dir::name_of(dir::WEST)").unwrap(), "WEST");
+    assert_eq!(engine.eval::<String>("dir::name_of(90)").unwrap(), "EAST");
+    assert_eq!(engine.eval::<String>("dir::name_of(false)").unwrap(), "OFF");
+    assert!(engine.eval::<Dynamic>("dir::name_of(1)").unwrap().is_unit());
+    assert!(engine.run("dir::EAST = 1;").is_err());
+}
+
+#[test]
+fn test_module_names() {
+    let mut module = Module::new();
+    assert_eq!(module.var_count(), 0);
+
+    module.set_var("b", 2 as INT);
+    module.set_var("a", 1 as INT);
+    module.set_sub_module("z", Module::new());
+    module.set_sub_module("y", Module::new());
+
+    assert_eq!(module.var_count(), 2);
+    assert_eq!(module.var_names().collect::<Vec<_>>(), ["a", "b"]);
+    assert_eq!(module.sub_module_names().collect::<Vec<_>>(), ["y", "z"]);
+}
+
+#[test]
+fn test_module_clear() {
+    let mut module = Module::new();
+    assert!(module.is_empty());
+
+    module.set_var("answer", 42 as INT);
+    module.set_native_fn("inc", |x: INT| Ok(x + 1));
+    module.set_sub_module("inner", Module::new());
+    module.build_index();
+    assert!(!module.is_empty());
+
+    module.clear();
+
+    assert!(module.is_empty());
+    assert_eq!(module.fn_count(), 0);
+    assert_eq!(module.var_count(), 0);
+    assert_eq!(module.sub_module_names().count(), 0);
+
+    module.set_var("answer", 1 as INT);
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.into());
+    assert_eq!(engine.eval::<INT>("m::answer").unwrap(), 1);
+    assert!(engine.eval::<INT>("m::inc(1)").is_err());
+}
+
+#[test]
+fn test_module_merge() {
+    let mut math = Module::new();
+    math.set_var("PI", 3 as INT);
+    math.set_var("name", "math");
+    math.set_native_fn("calc", |x: INT| Ok(x + 1));
+
+    let mut strings = Module::new();
+    strings.set_var("name", "strings");
+    strings.set_native_fn("calc", |x: INT| Ok(x * 2));
+    strings.set_native_fn("len", |s: ImmutableString| Ok(s.len() as INT));
+
+    let mut module = Module::new();
+    module.merge(&math).merge(&strings);
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.into());
+
+    assert_eq!(engine.eval::<INT>("m::PI").unwrap(), 3);
+    assert_eq!(engine.eval::<String>("m::name").unwrap(), "strings");
+    assert_eq!(engine.eval::<INT>("m::calc(21)").unwrap(), 42);
+    assert_eq!(engine.eval::<INT>(r#"m::len("hello")"#).unwrap(), 5);
+}
+
+#[cfg(not(feature = "no_function"))]
+#[test]
+fn test_module_merge_checked() {
+    let engine = Engine::new();
+
+    let mut module = Module::eval_ast_as_new(Scope::new(), &engine.compile("fn foo(x) { x } fn bar() { 1 } fn baz(x, y) { 0 }").unwrap(), &engine).unwrap();
+    module.set_native_fn("qux", |x: INT| Ok(x));
+
+    let mut other = Module::eval_ast_as_new(Scope::new(), &engine.compile("fn foo(x) { x + 1 } fn bar() { 2 } fn qux(x) { x }").unwrap(), &engine).unwrap();
+    other.set_native_fn("baz", |x: INT, y: INT| Ok(x + y));
+
+    assert_eq!(module.merge_checked(&other), [("bar".to_string(), 0), ("foo".to_string(), 1)]);
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.into());
+    assert_eq!(engine.eval::<INT>("m::foo(1)").unwrap(), 2);
+}
+
+#[test]
+fn test_module_resolver_alias() {
+    let mut engine = Engine::new();
+
+    let mut module = Module::new();
+    module.set_var("answer", 42 as INT);
+
+    let mut resolver = StaticModuleResolver::new();
+    resolver.insert("hello", module);
+    resolver.add_alias("hi", "hello");
+    resolver.add_alias("hey", "hi");
+    resolver.add_alias("ping", "pong");
+    resolver.add_alias("pong", "ping");
+
+    assert!(resolver.contains_path("hey"));
+    assert!(!resolver.contains_path("ping"));
+    engine.set_module_resolver(resolver);
+
+    assert_eq!(engine.eval::<INT>(r#"import "hey" as h; h::answer"#).unwrap(), 42);
+    assert!(matches!(*engine.run(r#"import "ping" as p;"#).unwrap_err(), EvalAltResult::ErrorModuleNotFound(..)));
+}
+
+#[test]
+fn test_module_display_fn() {
+    use std::sync::{Arc, RwLock};
+
+    #[derive(Clone)]
+    struct Point(INT, INT);
+
+    let mut module = Module::new();
+    module.set_native_fn("point", |x: INT, y: INT| Ok(Point(x, y)));
+    module.set_display_fn(|p: &Point| format!("<{}, {}>", p.0, p.1));
+
+    let mut engine = Engine::new();
+    engine.register_global_module(module.into());
+
+    assert_eq!(engine.eval::<String>("point(1, 2).to_string()").unwrap(), "<1, 2>");
+    assert_eq!(engine.eval::<String>("`p = ${point(3, 4)}`").unwrap(), "p = <3, 4>");
+    assert_eq!(engine.eval::<String>("42.to_string()").unwrap(), "42");
+
+    let output = Arc::new(RwLock::new(Vec::<String>::new()));
+    let logger = output.clone();
+    engine.on_print(move |s| logger.write().unwrap().push(s.to_string()));
+    engine.run("print(point(5, 6))").unwrap();
+    assert_eq!(*output.read().unwrap(), ["<5, 6>"]);
+
+    // A registered `to_string` function takes precedence
+    engine.register_fn("to_string", |p: &mut Point| format!("({}, {})", p.0, p.1));
+    assert_eq!(engine.eval::<String>("point(1, 2).to_string()").unwrap(), "(1, 2)");
+
+    // Display functions do not override the standard formatting of primitive types
+    let mut module = Module::new();
+    module.set_display_fn(|x: &INT| format!("#{x}"));
+
+    let mut engine = Engine::new();
+    engine.register_global_module(module.into());
+    assert_eq!(engine.eval::<String>("`${42}`").unwrap(), "42");
+
+    let mut module = Module::new();
+    module.set_native_fn("point", |x: INT, y: INT| Ok(Point(x, y)));
+    module.set_display_fn(|p: &Point| format!("<{}, {}>", p.0, p.1));
+
+    let mut resolver = StaticModuleResolver::new();
+    resolver.insert("geometry", module);
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+    assert_eq!(engine.eval::<String>(r#"import "geometry" as g; `${g::point(7, 8)}`"#).unwrap(), "<7, 8>");
+}
+
+#[test]
+fn test_module_resolver_versioned() {
+    use rhai::ModuleResolver;
+
+    let mut resolver = rhai::module_resolvers::VersionedModuleResolver::new();
+
+    for (version, value) in [("0.9.0", 1), ("1.2.0", 2), ("1.2.5", 3), ("1.4.1", 4), ("2.0.0", 5)] {
+        let mut module = Module::new();
+        module.set_var("value", value as INT);
+        resolver.add_version("math", version, module);
+    }
+
+    assert!(resolver.try_add_version("math", "1.x", Module::new()).is_none());
+    assert_eq!(resolver.versions("math").count(), 5);
+
+    assert!(resolver.can_resolve("math@~1.2"));
+    assert!(!resolver.can_resolve("math@^3"));
+    assert!(!resolver.can_resolve("algebra"));
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+
+    let eval = |path: &str| engine.eval::<INT>(&format!(r#"import "{path}" as m; m::value"#));
+
+    assert_eq!(eval("math").unwrap(), 5);
+    assert_eq!(eval("math@^1.2").unwrap(), 4);
+    assert_eq!(eval("math@~1.2").unwrap(), 3);
+    assert_eq!(eval("math@=1.2.0").unwrap(), 2);
+    assert_eq!(eval("math@<1").unwrap(), 1);
+    assert!(matches!(*eval("math@^3").unwrap_err(), EvalAltResult::ErrorInModule(..)));
+    assert!(matches!(*eval("math@^x").unwrap_err(), EvalAltResult::ErrorInModule(..)));
+    assert!(matches!(*eval("algebra@1").unwrap_err(), EvalAltResult::ErrorModuleNotFound(..)));
+}
+
+#[test]
+fn test_module_check_args() {
+    let mut module = Module::new();
+    let hash = module.set_native_fn("calc", |x: INT, _: ImmutableString, _: Dynamic| Ok(x));
+
+    assert!(module.check_args(hash, &[&Dynamic::from(1 as INT), &"hello".into(), &Dynamic::TRUE]).is_ok());
+    assert!(matches!(*module.check_args(0, &[]).unwrap_err(), EvalAltResult::ErrorFunctionNotFound(..)));
+
+    let err = module.check_args(hash, &[&Dynamic::from(1 as INT), &Dynamic::TRUE, &Dynamic::UNIT]).unwrap_err();
+    match *err {
+        EvalAltResult::ErrorInFunctionCall(ref name, _, ref err, _) => {
+            assert_eq!(name, "calc");
+            match **err {
+                EvalAltResult::ErrorMismatchDataType(ref expected, ref actual, _) => {
+                    assert_eq!(expected, "string");
+                    assert_eq!(actual, "bool for parameter #2");
+                }
+                _ => panic!("{err}"),
+            }
+        }
+        _ => panic!("{err}"),
+    }
+
+    assert!(module.check_args(hash, &[&Dynamic::from(1 as INT)]).is_err());
+}
+
+#[cfg(not(feature = "no_function"))]
+#[test]
+fn test_module_api_fingerprint() {
+    let engine = Engine::new();
+
+    let make = |script: &str| Module::eval_ast_as_new(Scope::new(), &engine.compile(script).unwrap(), &engine).unwrap();
+
+    let module = make("export const X = 1; fn foo(x) { x } fn bar() { 1 } private fn hidden() {}");
+    let same = make("fn bar() { 42 } export const X = 999; fn foo(y) { y * 2 }");
+    assert_eq!(module.api_fingerprint(), same.api_fingerprint());
+
+    assert_ne!(module.api_fingerprint(), make("export const Y = 1; fn foo(x) { x } fn bar() { 1 }").api_fingerprint());
+    assert_ne!(module.api_fingerprint(), make("export const X = 1; fn foo(x, y) { x } fn bar() { 1 }").api_fingerprint());
+
+    let mut nested = make("export const X = 1; fn foo(x) { x } fn bar() { 1 }");
+    nested.set_sub_module("inner", Module::new());
+    assert_ne!(module.api_fingerprint(), nested.api_fingerprint());
+}
+
+#[test]
+fn test_module_import_matching() {
+    let mut module = Module::new();
+    module.set_var("math_pi", 3 as INT);
+    module.set_var("text_sep", ",".to_string());
+    module.set_native_fn("math_double", |x: INT| Ok(x * 2));
+    module.set_native_fn("math_sq", |x: INT| Ok(x * x));
+    module.set_native_fn("math_id", |x: Dynamic| Ok(x));
+    module.set_native_fn("text_len", |s: &str| Ok(s.len() as INT));
+
+    let mut resolver = StaticModuleResolver::new();
+    resolver.insert("utils", module);
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+
+    let mut scope = Scope::new();
+    let functions = engine.import_matching(&mut scope, "utils", "math_*").unwrap();
+    assert!(scope.contains("math_pi"));
+    assert!(!scope.contains("text_sep"));
+    engine.register_global_module(functions.into());
+
+    assert_eq!(engine.eval_with_scope::<INT>(&mut scope, "math_sq(math_double(math_pi))").unwrap(), 36);
+    assert_eq!(engine.eval::<String>(r#"math_id("hello")"#).unwrap(), "hello");
+    assert!(engine.eval::<INT>(r#"text_len("hello")"#).is_err());
+
+    let mut scope = Scope::new();
+    engine.import_matching(&mut scope, "utils", "text_?e?").unwrap();
+    assert!(scope.contains("text_sep"));
+
+    assert!(matches!(*engine.import_matching(&mut scope, "utils", "foo*").unwrap_err(), EvalAltResult::ErrorInModule(..)));
+    assert!(matches!(*engine.import_matching(&mut scope, "missing", "*").unwrap_err(), EvalAltResult::ErrorModuleNotFound(..)));
+}
+
+#[test]
+fn test_module_on_var_read() {
+    use std::sync::{Arc, Mutex};
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let log2 = log.clone();
+
+    let mut sub_module = Module::new();
+    sub_module.set_var("depth", 1 as INT);
+    sub_module.on_var_read(|name| (name == "depth").then(|| Dynamic::from(2 as INT)));
+
+    let mut module = Module::new();
+    module.set_var("answer", 41 as INT);
+    module.set_var("secret", 0 as INT);
+    module.set_sub_module("inner", sub_module);
+    module.on_var_read(move |name| {
+        log2.lock().unwrap().push(name.to_string());
+        (name == "secret").then(|| Dynamic::from(1 as INT))
+    });
+
+    assert_eq!(module.get_var_value::<INT>("secret").unwrap(), 1);
+    log.lock().unwrap().clear();
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.into());
+
+    assert_eq!(engine.eval::<INT>("m::answer + m::secret").unwrap(), 42);
+    assert_eq!(engine.eval::<INT>("m::inner::depth").unwrap(), 2);
+    assert_eq!(*log.lock().unwrap(), ["answer", "secret"]);
+}
+
+#[test]
+fn test_module_rebind_vars() {
+    let mut module = Module::new();
+    module.set_var("x", 1 as INT);
+    module.set_var("y", 2 as INT);
+    module.build_index();
+
+    let previous = module.rebind_vars([("x", Dynamic::from("one")), ("z", Dynamic::from(3 as INT)), ("x", Dynamic::from(10 as INT))]);
+
+    assert_eq!(previous.len(), 1);
+    assert_eq!(previous["x"].as_int().unwrap(), 1);
+    assert_eq!(module.get_var_value::<INT>("x").unwrap(), 10);
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.clone().into());
+    assert_eq!(engine.eval::<INT>("m::x + m::y + m::z").unwrap(), 15);
+
+    module.rebind_vars(previous);
+    assert_eq!(module.get_var_value::<INT>("x").unwrap(), 1);
+    assert_eq!(module.get_var_value::<INT>("z").unwrap(), 3);
+}
+
+#[test]
+fn test_module_merge_with_strategy() {
+    use rhai::MergeStrategy;
+
+    let mut sub_module = Module::new();
+    sub_module.set_var("depth", 1 as INT);
+
+    let mut module = Module::new();
+    module.set_var("x", 1 as INT);
+    module.set_native_fn("f", |x: INT| Ok(x));
+    module.set_sub_module("inner", sub_module.clone());
+
+    let mut other = Module::new();
+    other.set_var("x", 10 as INT);
+    other.set_var("y", 2 as INT);
+    other.set_native_fn("f", |x: INT| Ok(x * 100));
+    other.set_native_fn("g", |x: INT| Ok(x));
+    sub_module.set_var("depth", 2 as INT);
+    other.set_sub_module("inner", sub_module);
+
+    let mut m = module.clone();
+    assert_eq!(m.merge_with_strategy(&other, &MergeStrategy::Error).unwrap_err(), ["f/1", "inner", "x"]);
+    assert!(!m.contains_var("y"));
+
+    let mut engine = Engine::new();
+
+    let mut m = module.clone();
+    m.merge_with_strategy(&other, &MergeStrategy::KeepExisting).unwrap();
+    engine.register_static_module("m", m.into());
+    assert_eq!(engine.eval::<INT>("m::x + m::y + m::f(3) + m::g(4) + m::inner::depth").unwrap(), 11);
+
+    let mut m = module.clone();
+    m.merge_with_strategy(&other, &MergeStrategy::Overwrite).unwrap();
+    engine.register_static_module("m", m.into());
+    assert_eq!(engine.eval::<INT>("m::x + m::y + m::f(3) + m::g(4) + m::inner::depth").unwrap(), 318);
+
+    let min = MergeStrategy::custom(|_, old, new| old.as_int().unwrap().min(new.as_int().unwrap()).into());
+    let mut m = module.clone();
+    m.merge_with_strategy(&other, &min).unwrap();
+    engine.register_static_module("m", m.into());
+    assert_eq!(engine.eval::<INT>("m::x + m::y + m::f(3) + m::g(4) + m::inner::depth").unwrap(), 308);
+}
+
+#[test]
+fn test_module_fn_var_args() {
+    let mut module = Module::new();
+    let hash = module.set_fn_var_args("sum", |_, args| Ok(args.iter().map(|a| a.as_int().unwrap()).sum::<INT>()));
+    module.set_native_fn("sum", |x: INT, y: INT| Ok(x * y));
+    assert!(module.contains_fn(hash));
+
+    let mut sub_module = Module::new();
+    sub_module.set_fn_var_args("count", |_, args| Ok(args.len() as INT));
+    module.set_sub_module("inner", sub_module);
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.clone().into());
+
+    assert_eq!(engine.eval::<INT>("m::sum(1, 2, 3, 4)").unwrap(), 10);
+    assert_eq!(engine.eval::<INT>("m::sum(6, 7)").unwrap(), 42);
+    assert_eq!(engine.eval::<INT>("m::sum()").unwrap(), 0);
+    assert_eq!(engine.eval::<INT>(r#"m::inner::count(1, "x", ())"#).unwrap(), 3);
+    assert!(engine.eval::<INT>("sum(1, 2, 3)").is_err());
+
+    engine.register_global_module(module.into());
+
+    assert_eq!(engine.eval::<INT>("sum(1, 2, 3)").unwrap(), 6);
+    assert_eq!(engine.eval::<INT>("sum(6, 7)").unwrap(), 42);
+}
+
+#[test]
+fn test_module_iter_fn_info() {
+    let mut module = Module::new();
+    assert_eq!(module.fn_count(), 0);
+
+    let hash_inc = module.set_native_fn("inc", |x: INT| Ok(x + 1));
+    let hash_add = module.set_native_fn("add", |x: INT, y: INT| Ok(x + y));
+    module.set_sub_module("inner", Module::new());
+
+    let mut info = module.iter_fn_info().collect::<Vec<_>>();
+    info.sort_by_key(|&(_, name, _)| name);
+
+    assert_eq!(module.fn_count(), 2);
+    assert_eq!(info, [(hash_add, "add", 2), (hash_inc, "inc", 1)]);
+    assert!(info.iter().all(|&(hash, ..)| module.contains_fn(hash)));
+}
+
+#[test]
+fn test_module_remove() {
+    let mut module = Module::new();
+    module.set_var("answer", 42 as INT);
+    let hash_inc = module.set_native_fn("inc", |x: INT| Ok(x + 1));
+    let hash_sum = module.set_fn_var_args("sum", |_, args| Ok(args.len() as INT));
+    module.set_sub_module("inner", Module::new());
+    module.build_index();
+
+    assert!(module.remove_fn(hash_inc));
+    assert!(!module.remove_fn(hash_inc));
+    assert!(!module.contains_fn(hash_inc));
+    assert!(module.remove_fn(hash_sum));
+    assert!(!module.contains_fn(hash_sum));
+    assert_eq!(module.remove_var("answer").unwrap().as_int().unwrap(), 42);
+    assert!(module.remove_var("answer").is_none());
+    assert!(!module.contains_var("answer"));
+    assert!(module.remove_sub_module("inner").is_some());
+    assert!(!module.contains_sub_module("inner"));
+    assert!(!module.is_indexed());
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.into());
+
+    assert!(matches!(*engine.eval::<INT>("m::inc(1)").unwrap_err(), EvalAltResult::ErrorFunctionNotFound(..)));
+    assert!(matches!(*engine.eval::<INT>("m::sum(1)").unwrap_err(), EvalAltResult::ErrorFunctionNotFound(..)));
+    assert!(matches!(*engine.eval::<INT>("m::answer").unwrap_err(), EvalAltResult::ErrorVariableNotFound(..)));
+}
+
+#[test]
+fn test_module_defer_import() {
+    let mut module = Module::new();
+    module.set_var("answer", 41 as INT);
+    module.set_native_fn("inc", |x: INT| Ok(x + 1));
+
+    let mut resolver = StaticModuleResolver::new();
+    resolver.insert("question", module);
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+
+    let mut scope = Scope::new();
+    engine.defer_import(&mut scope, "question", "q");
+    engine.defer_import(&mut scope, "missing", "m");
+
+    assert_eq!(engine.eval_with_scope::<INT>(&mut scope, "q::inc(q::answer)").unwrap(), 42);
+    assert_eq!(engine.eval_with_scope::<INT>(&mut scope, "let x = 0; if x > 0 { m::foo } else { q::answer }").unwrap(), 41);
+    assert!(matches!(*engine.eval_with_scope::<INT>(&mut scope, "m::foo").unwrap_err(), EvalAltResult::ErrorModuleNotFound(..)));
+    assert_eq!(engine.eval_with_scope::<INT>(&mut scope, r#"import "question" as m; m::answer"#).unwrap(), 41);
+}
+
+#[test]
+fn test_module_resolver_caching() {
+    use rhai::module_resolvers::CachingModuleResolver;
+    use rhai::{ModuleResolver, Position, Shared};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingResolver(StaticModuleResolver, AtomicUsize);
+
+    impl ModuleResolver for CountingResolver {
+        fn resolve(&self, engine: &Engine, source: Option<&str>, path: &str, pos: Position) -> Result<Shared<Module>, Box<EvalAltResult>> {
+            self.1.fetch_add(1, Ordering::Relaxed);
+            self.0.resolve(engine, source, path, pos)
+        }
+    }
+
+    let mut module = Module::new();
+    module.set_var("answer", 42 as INT);
+
+    let mut resolver = StaticModuleResolver::new();
+    resolver.insert("hello", module);
+
+    let mut resolver = CachingModuleResolver::new(CountingResolver(resolver, AtomicUsize::new(0)));
+    let engine = Engine::new();
+
+    let m1 = resolver.resolve(&engine, None, "hello", Position::NONE).unwrap();
+    let m2 = resolver.resolve(&engine, None, "hello", Position::NONE).unwrap();
+    assert_eq!(m1.get_var_value::<INT>("answer").unwrap(), 42);
+    assert_eq!(m2.get_var_value::<INT>("answer").unwrap(), 42);
+    assert_eq!(resolver.resolver().1.load(Ordering::Relaxed), 1);
+    assert!(resolver.is_cached("hello"));
+
+    assert!(resolver.resolve(&engine, None, "world", Position::NONE).is_err());
+    assert!(!resolver.is_cached("world"));
+    assert_eq!(resolver.resolver().1.load(Ordering::Relaxed), 2);
+
+    assert!(resolver.invalidate("hello").is_some());
+    resolver.resolve(&engine, None, "hello", Position::NONE).unwrap();
+    assert_eq!(resolver.resolver().1.load(Ordering::Relaxed), 3);
+
+    resolver.clear_cache();
+    assert!(!resolver.is_cached("hello"));
+
+    let errors = resolver.warm(&engine, &["hello", "world", "again"]).unwrap_err();
+    assert_eq!(errors.iter().map(|(path, ..)| path.as_str()).collect::<Vec<_>>(), ["world", "again"]);
+    assert!(errors.iter().all(|(.., err)| matches!(**err, EvalAltResult::ErrorModuleNotFound(..))));
+    assert!(resolver.is_cached("hello"));
+    assert!(!resolver.is_cached("world"));
+    assert_eq!(resolver.resolver().1.load(Ordering::Relaxed), 6);
+    assert!(resolver.warm(&engine, &["hello"]).is_ok());
+    assert_eq!(resolver.resolver().1.load(Ordering::Relaxed), 6);
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+    assert_eq!(engine.eval::<INT>(r#"import "hello" as a; import "hello" as b; a::answer + b::answer"#).unwrap(), 84);
+}
+
+#[test]
+fn test_module_resolver_ast_caching() {
+    use rhai::module_resolvers::AstCachingResolver;
+    use rhai::ModuleResolver;
+
+    let mut resolver = AstCachingResolver::new();
+    resolver.set_source("hello", "export let x = 41;");
+    resolver.set_source("same", "export let x = 41;");
+    resolver.set_source("bad", "let x = ;");
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+
+    assert_eq!(engine.eval::<INT>(r#"import "hello" as h; h::x + 1"#).unwrap(), 42);
+    assert_eq!(engine.eval::<INT>(r#"import "same" as h; h::x"#).unwrap(), 41);
+    assert!(matches!(*engine.run(r#"import "bad" as b;"#).unwrap_err(), EvalAltResult::ErrorInModule(..)));
+    assert!(matches!(*engine.run(r#"import "missing" as m;"#).unwrap_err(), EvalAltResult::ErrorModuleNotFound(..)));
+
+    let mut resolver = AstCachingResolver::new();
+    resolver.set_source("hello", "export let x = 1;");
+    resolver.set_source("same", "export let x = 1;");
+
+    let engine = Engine::new();
+    resolver.resolve(&engine, None, "hello", rhai::Position::NONE).unwrap();
+    assert!(resolver.is_cached("same"));
+    assert_eq!(resolver.cache_len(), 1);
+
+    resolver.set_source("hello", "export let x = 2;");
+    assert!(!resolver.is_cached("hello"));
+    let module = resolver.resolve(&engine, None, "hello", rhai::Position::NONE).unwrap();
+    assert_eq!(module.get_var_value::<INT>("x").unwrap(), 2);
+    assert_eq!(resolver.cache_len(), 2);
+
+    resolver.remove_source("same");
+    resolver.prune_cache();
+    assert_eq!(resolver.cache_len(), 1);
+    assert!(resolver.is_cached("hello"));
+}
+
+#[test]
+fn test_module_referenced_types() {
+    use std::any::TypeId;
+
+    #[derive(Clone)]
+    struct Point(INT, INT);
+
+    let mut module = Module::new();
+    assert!(module.referenced_types().is_empty());
+
+    module.set_native_fn("make", |x: INT, y: INT| Ok(Point(x, y)));
+    module.set_native_fn("label", |p: &mut Point, s: &str| Ok(format!("{s}: {}, {}", p.0, p.1)));
+    module.set_native_fn("any", |x: Dynamic| Ok(x));
+    module.set_native_fn("reset", |_: &mut Point| Ok(()));
+    module.set_native_fn("origin", || Ok(Point(0, 0)));
+    module.set_native_fn("len", |p: &mut Point| Ok(p.0 as i32));
+    module.set_raw_fn("raw", [TypeId::of::<char>()], |_, _| Ok(Dynamic::from(true)));
+    module.set_raw_fn_with_return_type::<u8>("raw_typed", [TypeId::of::<char>()], |_, _| Ok(Dynamic::from(0_u8)));
+
+    let mut sub_module = Module::new();
+    sub_module.set_native_fn("flag", |b: bool| Ok(b));
+    module.set_sub_module("inner", sub_module);
+
+    let types = module.referenced_types();
+    assert_eq!(types.len(), 6);
+    assert!(types.contains(&TypeId::of::<INT>()));
+    assert!(types.contains(&TypeId::of::<Point>()));
+    assert!(types.contains(&TypeId::of::<ImmutableString>()));
+    assert!(types.contains(&TypeId::of::<i32>()));
+    assert!(types.contains(&TypeId::of::<char>()));
+    assert!(types.contains(&TypeId::of::<u8>()));
+    assert!(!types.contains(&TypeId::of::<bool>()));
+}
+
+#[cfg(not(feature = "no_function"))]
+#[cfg(not(feature = "no_position"))]
+#[test]
+fn test_module_script_fn_position() {
+    let engine = Engine::new();
+
+    let ast = engine.compile("let x = 1;\n\nfn foo(x) { x }\n  fn bar(x, y = 2) { x + y }").unwrap();
+    let module = Module::eval_ast_as_new(Scope::new(), &ast, &engine).unwrap();
+
+    let pos = module.get_script_fn_position("foo", 1).unwrap();
+    assert_eq!((pos.line(), pos.position()), (Some(3), Some(1)));
+
+    // Overloads for default parameters share the position of the definition
+    for num_params in [1, 2] {
+        let pos = module.get_script_fn_position("bar", num_params).unwrap();
+        assert_eq!((pos.line(), pos.position()), (Some(4), Some(3)));
+        assert_eq!(module.get_script_fn("bar", num_params).unwrap().position(), pos);
+    }
+
+    assert!(module.get_script_fn_position("foo", 2).is_none());
+}
+
+#[test]
+fn test_module_resolver_from_sources() {
+    let mut engine = Engine::new();
+
+    let resolver = StaticModuleResolver::from_sources(&engine, &[("hello", "export const ANSWER = 41;"), ("world", "let x = 1; export x as ONE;")]).unwrap();
+    assert_eq!(resolver.len(), 2);
+    engine.set_module_resolver(resolver);
+
+    assert_eq!(engine.eval::<INT>(r#"import "hello" as h; import "world" as w; h::ANSWER + w::ONE"#).unwrap(), 42);
+
+    let (path, err) = StaticModuleResolver::from_sources(&engine, &[("good", "export const X = 1;"), ("bad", "let x = ;")]).unwrap_err();
+    assert_eq!(path, "bad");
+    assert!(matches!(*err, EvalAltResult::ErrorParsing(..)));
+
+    let (path, err) = StaticModuleResolver::from_sources(&engine, &[("oops", r#"throw "oops";"#)]).unwrap_err();
+    assert_eq!(path, "oops");
+    assert!(matches!(*err, EvalAltResult::ErrorRuntime(..)));
+}
+
+#[test]
+fn test_module_resolver_can_resolve() {
+    use rhai::module_resolvers::ModuleResolversCollection;
+    use rhai::ModuleResolver;
+
+    let mut resolver = StaticModuleResolver::new();
+    resolver.insert("hello", Module::new());
+
+    assert!(resolver.can_resolve("hello"));
+    assert!(!resolver.can_resolve("world"));
+    assert!(!DummyModuleResolver::new().can_resolve("hello"));
+
+    let mut collection = ModuleResolversCollection::new();
+    collection.push(DummyModuleResolver::new());
+    assert!(!collection.can_resolve("hello"));
+    collection.push(resolver);
+    assert!(collection.can_resolve("hello"));
+
+    #[cfg(not(feature = "no_std"))]
+    #[cfg(not(target_family = "wasm"))]
+    {
+        let resolver = rhai::module_resolvers::FileModuleResolver::new_with_path("scripts");
+
+        assert!(resolver.can_resolve("loop"));
+        assert!(!resolver.can_resolve("does_not_exist"));
+    }
+}
+
+/// A temporary directory for module script files, removed when dropped.
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+struct TempDir(std::path::PathBuf);
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("rhai-test-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        Self(dir)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+impl std::ops::Deref for TempDir {
+    type Target = std::path::Path;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_module_circular_import() {
+    use rhai::module_resolvers::{FileModuleResolver, ModuleResolversCollection};
+
+    let dir = TempDir::new("circular-import");
+    std::fs::write(dir.join("a.rhai"), r#"import "b" as b; export const x = 1;"#).unwrap();
+    std::fs::write(dir.join("b.rhai"), r#"import "./a" as a; export const y = 2;"#).unwrap();
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(FileModuleResolver::new_with_path(dir.to_path_buf()));
+
+    let mut err = engine.run(r#"import "a" as a;"#).unwrap_err();
+    let mut modules = Vec::new();
+
+    while let EvalAltResult::ErrorInModule(path, inner, ..) = *err {
+        modules.push(path);
+        err = inner;
+    }
+
+    // Different paths to the same file are the same module
+    assert_eq!(modules, ["a", "b"]);
+    assert!(matches!(*err, EvalAltResult::ErrorCircularImport(ref path, ..) if path == "./a"));
+
+    // Module resolvers in a collection see the modules being imported
+    let mut resolvers = ModuleResolversCollection::new();
+    resolvers.push(StaticModuleResolver::new());
+    resolvers.push(FileModuleResolver::new_with_path(dir.to_path_buf()));
+    engine.set_module_resolver(resolvers);
+
+    let err = engine.run(r#"import "a" as a;"#).unwrap_err();
+    assert!(matches!(*err, EvalAltResult::ErrorCircularImport(ref path, ..) if path == "./a"));
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_module_circular_import_relative() {
+    use rhai::module_resolvers::FileModuleResolver;
+
+    let dir = TempDir::new("circular-import-relative");
+    std::fs::create_dir_all(dir.join("a").join("b").join("b")).unwrap();
+    std::fs::write(dir.join("a").join("m.rhai"), r#"import "./b/m" as m; export const x = m::x;"#).unwrap();
+    std::fs::write(dir.join("a").join("b").join("m.rhai"), r#"import "./b/m" as m; export const x = m::x;"#).unwrap();
+    std::fs::write(dir.join("a").join("b").join("b").join("m.rhai"), "export const x = 42;").unwrap();
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(FileModuleResolver::new_with_path(dir.to_path_buf()).with_relative_to_importer(true));
+
+    // The same import path from different directories is not a circular import
+    assert_eq!(engine.eval::<INT>(r#"import "a/m" as m; m::x"#).unwrap(), 42);
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_module_resolver_collection_fallback() {
+    use rhai::module_resolvers::{FileModuleResolver, ModuleResolversCollection};
+
+    let mut module = Module::new();
+    module.set_var("foo", 42 as INT);
+
+    let mut resolver = StaticModuleResolver::new();
+    resolver.insert("hello", module);
+
+    let mut collection = ModuleResolversCollection::new();
+    collection.push(resolver);
+    collection.push(FileModuleResolver::new_with_path("scripts"));
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(collection);
+
+    assert_eq!(engine.eval::<INT>(r#"import "hello" as m; m::foo"#).unwrap(), 42);
+    assert_eq!(engine.eval::<INT>(r#"import "loop" as m; m::foo"#).unwrap(), 0);
+
+    let err = engine.run(r#"import "does_not_exist" as m;"#).unwrap_err();
+    assert!(matches!(&*err, EvalAltResult::ErrorModuleFileNotFound(path, paths, ..) if path == "does_not_exist" && paths.len() == 1));
+    #[cfg(not(feature = "no_position"))]
+    assert_eq!(err.position(), rhai::Position::new(1, 8));
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_module_resolver_dry_run() {
+    use std::sync::{Arc, RwLock};
+
+    let output = Arc::new(RwLock::new(Vec::<String>::new()));
+    let logger = output.clone();
+
+    let mut resolver = rhai::module_resolvers::FileModuleResolver::new_with_path("scripts").with_dry_run(true);
+    resolver.on_print(move |s| logger.write().unwrap().push(s.to_string()));
+    assert!(resolver.is_dry_run());
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+
+    // The loop counting `x` down to zero is skipped
+    assert_eq!(engine.eval::<INT>(r#"import "loop" as m; m::foo"#).unwrap(), 10);
+    assert!(output.read().unwrap().is_empty());
+
+    // The `import` and `print` statements in the module script are skipped
+    engine.run(r#"import "module" as m;"#).unwrap();
+    assert!(output.read().unwrap().is_empty());
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+#[cfg(not(feature = "no_function"))]
+#[test]
+fn test_module_resolver_print_and_debug() {
+    use rhai::module_resolvers::FileModuleResolver;
+    use std::sync::{Arc, RwLock};
+
+    let dir = TempDir::new("print-debug");
+    std::fs::write(dir.join("noisy.rhai"), r#"print("loading"); debug("loaded"); fn hello() { print("hello"); debug("world"); }"#).unwrap();
+
+    let output = Arc::new(RwLock::new(Vec::<String>::new()));
+
+    let mut resolver = FileModuleResolver::new_with_path(dir.to_path_buf());
+    let logger = output.clone();
+    resolver.on_print(move |s| logger.write().unwrap().push(format!("resolver print: {s}")));
+    let logger = output.clone();
+    resolver.on_debug(move |s, src, _| logger.write().unwrap().push(format!("resolver debug: {s} @ {}", src.unwrap_or(""))));
+
+    let mut engine = Engine::new();
+    let logger = output.clone();
+    engine.on_print(move |s| logger.write().unwrap().push(format!("engine print: {s}")));
+    let logger = output.clone();
+    engine.on_debug(move |s, _, _| logger.write().unwrap().push(format!("engine debug: {s}")));
+    engine.set_module_resolver(resolver);
+
+    engine.run(r#"import "noisy" as m; m::hello();"#).unwrap();
+
+    assert_eq!(*output.read().unwrap(), ["resolver print: loading", r#"resolver debug: "loaded" @ noisy"#, "engine print: hello", r#"engine debug: "world""#]);
+}
+
+#[test]
+fn test_module_error_source_chain() {
+    use std::error::Error;
+
+    let err = EvalAltResult::ErrorInModule(
+        "a".into(),
+        EvalAltResult::ErrorInModule("b".into(), EvalAltResult::ErrorModuleNotFound("c".into(), rhai::Position::NONE).into(), rhai::Position::NONE).into(),
+        rhai::Position::NONE,
+    );
+
+    let mut chain = Vec::new();
+    let mut source: Option<&dyn Error> = Some(&err);
+
+    while let Some(err) = source {
+        chain.push(err.to_string());
+        source = err.source();
+    }
+
+    assert_eq!(chain.len(), 3);
+    assert!(chain[2].contains("c"));
+}
+
+#[test]
+fn test_module_convert_fn() {
+    #[derive(Debug, Clone)]
+    struct Meters(INT);
+    #[derive(Debug, Clone)]
+    struct Feet(INT);
+
+    let mut module = Module::new();
+    module.set_native_fn("meters", |x: INT| Ok(Meters(x)));
+    module.set_native_fn("value", |x: Feet| Ok(x.0));
+    module.set_native_fn("value", |x: Meters| Ok(x.0));
+    module.set_convert_fn(|x: Meters| Ok(Feet(x.0 * 3)));
+    module.set_convert_fn(|x: Meters| Ok(x.0 * 100));
+    module.set_convert_fn(|x: Feet| if x.0 >= 0 { Ok(Meters(x.0 / 3)) } else { Err("negative length".into()) });
+
+    let mut engine = Engine::new();
+    engine.register_global_module(module.into());
+
+    assert_eq!(engine.eval::<INT>(r#"value(convert(meters(14), "Feet"))"#).unwrap(), 42);
+    assert_eq!(engine.eval::<INT>(&format!(r#"convert(meters(14), "{}")"#, std::any::type_name::<INT>())).unwrap(), 1400);
+    assert_eq!(engine.eval::<INT>(r#"value(convert(convert(meters(14), "Feet"), "Meters"))"#).unwrap(), 14);
+    assert!(matches!(*engine.eval::<INT>(r#"convert(meters(14), "Inches")"#).unwrap_err(), EvalAltResult::ErrorMismatchDataType(..)));
+    assert!(matches!(*engine.eval::<INT>(r#"convert(convert(meters(-1), "Feet"), "Meters")"#).unwrap_err(), EvalAltResult::ErrorRuntime(..)));
+}
+
+#[test]
+fn test_module_unregister_global() {
+    let mut module1 = Module::new();
+    module1.set_native_fn("calc", |x: INT| Ok(x + 1));
+    module1.set_native_fn("double", |x: INT| Ok(x * 2));
+    let module1: rhai::Shared<Module> = module1.into();
+
+    let mut module2 = Module::new();
+    module2.set_native_fn("calc", |x: INT| Ok(x - 1));
+    let module2: rhai::Shared<Module> = module2.into();
+
+    let mut engine = Engine::new();
+    engine.register_global_module(module1.clone());
+    engine.register_global_module(module2.clone());
+
+    assert_eq!(engine.eval::<INT>("calc(43)").unwrap(), 42);
+
+    // Functions overridden by a later module stay
+    assert!(engine.unregister_global_module(&module1));
+    assert!(!engine.unregister_global_module(&module1));
+    assert_eq!(engine.eval::<INT>("calc(43)").unwrap(), 42);
+    assert!(engine.eval::<INT>("double(21)").is_err());
+
+    // Functions overridden by this module become visible again
+    engine.register_global_module(module1.clone());
+    engine.register_global_module(module2.clone());
+    assert!(engine.unregister_global_module(&module2));
+    assert_eq!(engine.eval::<INT>("calc(41)").unwrap(), 42);
+}
+
+#[test]
+fn test_module_to_script() {
+    let engine = Engine::new();
+
+    let mut module = Module::new();
+    module.set_var("answer", 42 as INT);
+    module.set_var("text", "hello\n\"world\"");
+    module.set_var("flag", true);
+    module.set_var("letter", 'x');
+    module.set_var("nothing", ());
+    #[cfg(not(feature = "no_index"))]
+    module.set_var("list", rhai::Array::from([Dynamic::from(1 as INT), Dynamic::from("two")]));
+    module.set_var("min", INT::MIN);
+    module.set_var("not valid", 1 as INT);
+    module.set_native_fn("calc", |x: INT, y: INT| Ok(x + y));
+
+    let script = module.to_script();
+    assert!(script.contains("// native fn calc(_, _)"));
+    assert!(script.contains("// export const \"not valid\": "));
+
+    let ast = engine.compile(&script).unwrap();
+    let module2 = Module::eval_ast_as_new(Scope::new(), &ast, &engine).unwrap();
+
+    assert_eq!(module2.get_var_value::<INT>("answer").unwrap(), 42);
+    assert_eq!(module2.get_var_value::<ImmutableString>("text").unwrap(), "hello\n\"world\"");
+    assert!(module2.get_var_value::<bool>("flag").unwrap());
+    assert_eq!(module2.get_var_value::<char>("letter").unwrap(), 'x');
+    assert!(module2.contains_var("nothing"));
+    assert_eq!(module2.get_var_value::<INT>("min").unwrap(), INT::MIN);
+    assert!(!module2.contains_var("not valid"));
+    #[cfg(not(feature = "no_index"))]
+    assert_eq!(module2.get_var_value::<rhai::Array>("list").unwrap().len(), 2);
+
+    #[cfg(not(feature = "no_function"))]
+    {
+        let ast = engine.compile("fn add(x, y) { x + y } private fn helper() { 42 }").unwrap();
+        // Only public functions are kept when evaluating a module
+        let module = Module::eval_ast_as_new(Scope::new(), &ast, &engine).unwrap();
+        let script = module.to_script();
+        assert!(script.contains("fn add(x, y) {"));
+        assert!(!script.contains("helper"));
+
+        // The functions library of the AST holds private functions as well
+        let module: &Module = ast.as_ref();
+        let script = module.to_script();
+        assert!(script.contains("fn add(x, y) {"));
+        assert!(script.contains("private fn helper() {"));
+
+        // Closures cannot be written back
+        let ast = engine.compile("fn adder() { |y| y + 1 }").unwrap();
+        let module: &Module = ast.as_ref();
+        assert!(module.to_script().contains("// fn adder() { ... }"));
+    }
+}
+
+#[cfg(not(feature = "no_function"))]
+#[test]
+fn test_module_to_script_round_trip() {
+    let engine = Engine::new();
+
+    let check = |script: &str, calls: &[&str]| {
+        let ast = engine.compile(script).unwrap();
+        let module: &Module = ast.as_ref();
+        let script2 = module.to_script();
+        assert!(!script2.contains("// "), "{script2}");
+
+        let ast2 = engine.compile(&script2).unwrap();
+        let module2: &Module = ast2.as_ref();
+        assert!(module.same_api(module2));
+
+        for call in calls {
+            let call = engine.compile(call).unwrap();
+            let value = engine.eval_ast::<Dynamic>(&ast.merge(&call)).unwrap();
+            let value2 = engine.eval_ast::<Dynamic>(&ast2.merge(&call)).unwrap();
+            assert_eq!(value2.to_string(), value.to_string());
+        }
+    };
+
+    check(
+        r#"
+            fn add(x, y = 2) { x + y }
+            private fn helper(n) { let r = 0; for (x, i) in 0..n { r += x * i; } r }
+            fn calc(n) {
+                let total = helper(n);
+                while total > 100 { total -= 7; }
+                do { total += 1; } until total % 5 == 0;
+                loop { if total < 50 { break; } total /= 2; }
+                let s = `total=${total}!`;
+                if total > 20 && !(total == 30) { s } else { -total }
+            }
+            fn check(x) { try { if x < 0 { throw "negative"; } x } catch (err) { err } }
+        "#,
+        &["add(40)", "add(40, 3)", "calc(20)", "calc(3)", "check(3)", "check(-1)"],
+    );
+
+    #[cfg(not(feature = "no_index"))]
+    #[cfg(not(feature = "no_object"))]
+    check(
+        r#"
+            fn int.double() { this * 2 }
+            fn pick(m, i) {
+                let a = [m.a[i], m["b c"][i]];
+                a[0] += 1;
+                a.push(-5);
+                a[0].double() + a[1] + a[2] + m.a.len()
+            }
+        "#,
+        &[r#"pick(#{a: [1, 2], "b c": [10, 20]}, 1)"#, "let x = 21; x.double()"],
+    );
+}
+
+#[test]
+fn test_module_metadata() {
+    let mut module = Module::new();
+    module.set_var("answer", 42 as INT);
+    module.set_metadata("source", "memory").set_metadata("version", 2 as INT);
+
+    assert_eq!(module.get_metadata("source").unwrap().clone().into_string().unwrap(), "memory");
+    assert_eq!(module.get_metadata("version").unwrap().as_int().unwrap(), 2);
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.into());
+
+    // Host metadata is not visible to scripts
+    assert_eq!(engine.eval::<INT>("m::answer").unwrap(), 42);
+    assert!(engine.eval::<INT>("m::version").is_err());
+}
+
+#[test]
+fn test_module_eval_isolated() {
+    let mut sandbox = Engine::new_raw();
+    sandbox.register_fn("allowed", || 40 as INT);
+
+    let mut engine = Engine::new();
+    engine.register_fn("secret", || 42 as INT);
+
+    let module = Module::eval_isolated(&sandbox, "export const X = allowed() + 2; fn leak() { secret() }").unwrap();
+    assert_eq!(module.get_var_value::<INT>("X").unwrap(), 42);
+    assert_eq!(module.count().1, 0);
+
+    assert!(matches!(*Module::eval_isolated(&sandbox, "export const X = secret();").unwrap_err(), EvalAltResult::ErrorFunctionNotFound(..)));
+
+    engine.register_static_module("m", module.into());
+    assert_eq!(engine.eval::<INT>("m::X").unwrap(), 42);
+}
+
+#[test]
+fn test_module_descendants() {
+    let mut sub_module2 = Module::new();
+    sub_module2.set_var("answer", 41 as INT);
+
+    let mut sub_module = Module::new();
+    sub_module.set_sub_module("universe", sub_module2);
+    sub_module.set_sub_module("everything", Module::new());
+
+    let mut module = Module::new();
+    module.set_sub_module("life", sub_module);
+    module.set_sub_module("death", Module::new());
+
+    let paths = module.descendants().map(|(path, _)| path.join("::")).collect::<Vec<_>>();
+    assert_eq!(paths, ["death", "life", "life::everything", "life::universe"]);
+
+    let found = module.descendants().find(|(_, m)| m.contains_var("answer")).map(|(path, _)| path.join("::"));
+    assert_eq!(found.as_deref(), Some("life::universe"));
+
+    module.descendants_mut(|_, m| {
+        if let Some(answer) = m.get_var_value::<INT>("answer") {
+            m.set_var("answer", answer + 1);
+        }
+    });
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.into());
+    assert_eq!(engine.eval::<INT>("m::life::universe::answer").unwrap(), 42);
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_module_resolver_sandboxed() {
+    use rhai::module_resolvers::FileModuleResolver;
+
+    let dir = TempDir::new("sandboxed");
+    let base = dir.join("base");
+    std::fs::create_dir_all(base.join("sub")).unwrap();
+    std::fs::write(base.join("sub").join("inner.rhai"), "export const x = 1;").unwrap();
+    std::fs::write(dir.join("outside.rhai"), "export const x = 2;").unwrap();
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(dir.join("outside.rhai"), base.join("link.rhai")).unwrap();
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(FileModuleResolver::new_with_path_sandboxed(base.clone()));
+
+    let absolute = dir.join("outside").to_string_lossy().replace('\\', "\\\\");
+
+    let result_inner = engine.eval::<INT>(r#"import "sub/inner" as m; m::x"#);
+    let result_dot_dot = engine.eval::<INT>(r#"import "sub/../sub/inner" as m; m::x"#);
+    let result_escape = engine.run(r#"import "../outside" as m;"#);
+    let result_absolute = engine.run(&format!(r#"import "{absolute}" as m;"#));
+    #[cfg(unix)]
+    let result_symlink = engine.run(r#"import "link" as m;"#);
+
+    engine.set_module_resolver(FileModuleResolver::new_with_path(base.clone()));
+    let result_unsandboxed = engine.eval::<INT>(r#"import "../outside" as m; m::x"#);
+
+    assert_eq!(result_inner.unwrap(), 1);
+    assert_eq!(result_dot_dot.unwrap(), 1);
+    assert!(matches!(*result_escape.unwrap_err(), EvalAltResult::ErrorModuleNotFound(ref path, ..) if path == "../outside"));
+    assert!(matches!(*result_absolute.unwrap_err(), EvalAltResult::ErrorModuleNotFound(..)));
+    #[cfg(unix)]
+    assert!(matches!(*result_symlink.unwrap_err(), EvalAltResult::ErrorModuleNotFound(ref path, ..) if path == "link"));
+    assert_eq!(result_unsandboxed.unwrap(), 2);
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_module_resolver_reader() {
+    use rhai::module_resolvers::FileModuleResolver;
+    use rhai::ModuleResolver;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    let base = PathBuf::from("virtual");
+
+    let mut files = HashMap::new();
+    files.insert(base.join("hello.rhai"), r#"import "sub/world" as w; export const answer = w::x * 2;"#.to_string());
+    files.insert(base.join("sub").join("world.rhai"), "export const x = 21;".to_string());
+
+    let resolver = FileModuleResolver::new_with_path(base).with_reader(move |path| files.get(path).cloned().ok_or_else(|| std::io::ErrorKind::NotFound.into()));
+
+    assert!(resolver.can_resolve("hello"));
+    assert!(!resolver.can_resolve("missing"));
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+
+    assert_eq!(engine.eval::<INT>(r#"import "hello" as m; m::answer"#).unwrap(), 42);
+    assert!(matches!(*engine.run(r#"import "missing" as m;"#).unwrap_err(), EvalAltResult::ErrorModuleFileNotFound(ref path, ..) if path == "missing"));
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_module_resolver_reader_sandboxed() {
+    use rhai::module_resolvers::FileModuleResolver;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    let base = PathBuf::from("virtual");
+
+    let mut files = HashMap::new();
+    files.insert(base.join("hello.rhai"), r#"import "sub/world" as w; export const answer = w::x * 2;"#.to_string());
+    files.insert(base.join("sub").join("world.rhai"), "export const x = 21;".to_string());
+    files.insert(base.join("..").join("outside.rhai"), "export const x = 1;".to_string());
+
+    let resolver = FileModuleResolver::new_with_path_sandboxed(base).with_reader(move |path| files.get(path).cloned().ok_or_else(|| std::io::ErrorKind::NotFound.into()));
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+
+    assert_eq!(engine.eval::<INT>(r#"import "hello" as m; m::answer"#).unwrap(), 42);
+    assert!(matches!(*engine.run(r#"import "../outside" as m;"#).unwrap_err(), EvalAltResult::ErrorModuleNotFound(ref path, ..) if path == "../outside"));
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_module_resolver_multiple_paths() {
+    use rhai::module_resolvers::FileModuleResolver;
+
+    let dir = TempDir::new("multiple-paths");
+    let (first, second) = (dir.join("first"), dir.join("second"));
+    std::fs::create_dir_all(&first).unwrap();
+    std::fs::create_dir_all(&second).unwrap();
+    std::fs::write(first.join("both.rhai"), "export const x = 1;").unwrap();
+    std::fs::write(second.join("both.rhai"), "export const x = 2;").unwrap();
+    std::fs::write(second.join("only.rhai"), "export const x = 3;").unwrap();
+
+    let mut resolver = FileModuleResolver::new_with_path(first.clone());
+    resolver.add_path(second.clone());
+
+    assert_eq!(resolver.search_paths("missing", None), [first.join("missing.rhai"), second.join("missing.rhai")]);
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+
+    let result_both = engine.eval::<INT>(r#"import "both" as m; m::x"#);
+    let result_only = engine.eval::<INT>(r#"import "only" as m; m::x"#);
+    let result_missing = engine.run(r#"import "missing" as m;"#);
+
+    assert_eq!(result_both.unwrap(), 1);
+    assert_eq!(result_only.unwrap(), 3);
+
+    let searched = [first.join("missing.rhai").to_string_lossy().to_string(), second.join("missing.rhai").to_string_lossy().to_string()];
+    assert!(matches!(*result_missing.unwrap_err(), EvalAltResult::ErrorModuleFileNotFound(ref path, ref paths, ..) if path == "missing" && *paths == searched));
+}
+
+#[test]
+fn test_module_contains_fn_by_name() {
+    let mut module = Module::new();
+    module.set_native_fn("calc", |x: INT, y: INT| Ok(x + y));
+    module.set_native_fn("calc", |x: &str| Ok(x.len() as INT));
+    module.set_fn_var_args("sum", |_, args| Ok(args.len() as INT));
+
+    assert!(module.contains_fn_by_name("calc", 1));
+    assert!(module.contains_fn_by_name("calc", 2));
+    assert!(!module.contains_fn_by_name("calc", 3));
+    assert!(module.contains_fn_by_name("sum", 0));
+    assert!(module.contains_fn_by_name("sum", 5));
+    assert!(!module.contains_fn_by_name("missing", 0));
+
+    #[cfg(not(feature = "no_function"))]
+    {
+        let engine = Engine::new();
+        let ast = engine.compile("fn hello(x) { x }").unwrap();
+        let module = Module::eval_ast_as_new(Scope::new(), &ast, &engine).unwrap();
+
+        assert!(module.contains_fn_by_name("hello", 1));
+        assert!(!module.contains_fn_by_name("hello", 0));
+    }
+}
+
+#[test]
+fn test_module_resolver_static_from_iter() {
+    let mut hello = Module::new();
+    hello.set_var("x", 1 as INT);
+    let mut world = Module::new();
+    world.set_var("x", 2 as INT);
+
+    let mut resolver: StaticModuleResolver = vec![("hello", hello), ("world", world)].into_iter().collect();
+
+    let mut other = Module::new();
+    other.set_var("x", 3 as INT);
+    resolver.extend([("other".to_string(), other)]);
+    resolver.insert("a", Module::new()).insert("b", Module::new());
+
+    assert_eq!(resolver.len(), 5);
+    assert!(resolver.contains_path("hello"));
+    assert!(resolver.contains_path("b"));
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+
+    assert_eq!(engine.eval::<INT>(r#"import "hello" as h; import "world" as w; import "other" as o; h::x + w::x + o::x"#).unwrap(), 6);
+}
+
+#[test]
+fn test_module_var_read_only() {
+    let mut resolver = StaticModuleResolver::new();
+    resolver.insert("hello", Module::eval_ast_as_new(Scope::new(), &Engine::new().compile("export const PI = 3; let x = 1; export x;").unwrap(), &Engine::new()).unwrap());
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+
+    assert_eq!(engine.eval::<INT>(r#"import "hello" as m; m::PI + m::x"#).unwrap(), 4);
+    assert!(matches!(*engine.run(r#"import "hello" as m; m::PI = 42;"#).unwrap_err(), EvalAltResult::ErrorAssignmentToConstant(..)));
+    assert!(matches!(*engine.run(r#"import "hello" as m; m::PI += 1;"#).unwrap_err(), EvalAltResult::ErrorAssignmentToConstant(..)));
+    assert!(matches!(*engine.run(r#"import "hello" as m; m::x = 42;"#).unwrap_err(), EvalAltResult::ErrorAssignmentToConstant(..)));
+    assert_eq!(engine.eval::<INT>(r#"import "hello" as m; m::PI"#).unwrap(), 3);
+}
+
+#[test]
+fn test_module_fn_param_types() {
+    use std::any::TypeId;
+
+    let mut module = Module::new();
+    let hash_calc = module.set_native_fn("calc", |x: INT, s: String| Ok(x + s.len() as INT));
+    let hash_inc = module.set_native_fn("inc", |x: &mut INT| {
+        *x += 1;
+        Ok(())
+    });
+
+    assert_eq!(module.fn_param_types(hash_calc).unwrap(), [TypeId::of::<INT>(), TypeId::of::<ImmutableString>()]);
+    assert_eq!(module.fn_param_types(hash_inc).unwrap(), [TypeId::of::<INT>()]);
+    assert!(module.fn_param_types(hash_calc ^ hash_inc).is_none());
+}
+
+#[test]
+fn test_module_combine_flatten_collisions() {
+    let mut first = Module::new();
+    first.set_native_fn("helper", || Ok(1 as INT));
+    first.set_native_fn("first_only", || Ok(10 as INT));
+
+    let mut second = Module::new();
+    second.set_native_fn("helper", || Ok(2 as INT));
+    second.set_native_fn("second_only", || Ok(20 as INT));
+
+    let mut parent = Module::new();
+    parent.combine_flatten(first.clone());
+    parent.combine_flatten(second.clone());
+
+    let mut engine = Engine::new();
+    engine.register_static_module("parent", parent.into());
+
+    assert_eq!(engine.eval::<INT>("parent::helper()").unwrap(), 2);
+    assert_eq!(engine.eval::<INT>("parent::first_only() + parent::second_only()").unwrap(), 30);
+
+    let mut nested = Module::new();
+    nested.set_sub_module("a", first);
+    nested.set_sub_module("b", second);
+
+    let mut parent = Module::new();
+    parent.combine_flatten(nested);
+
+    engine.register_static_module("parent", parent.into());
+
+    assert_eq!(engine.eval::<INT>("parent::helper()").unwrap(), 2);
+    assert!(engine.eval::<INT>("parent::a::helper()").is_err());
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_module_resolver_not_found_searched() {
+    use rhai::module_resolvers::FileModuleResolver;
+    use std::path::Path;
+
+    let resolver = FileModuleResolver::new_with_path_and_extension("scripts", "x");
+    assert_eq!(resolver.search_paths("does_not_exist", None), [Path::new("scripts").join("does_not_exist.x")]);
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+
+    let err = engine.run(r#"import "does_not_exist" as m;"#).unwrap_err();
+    let file_path = Path::new("scripts").join("does_not_exist.x").to_string_lossy().to_string();
+
+    assert!(matches!(*err, EvalAltResult::ErrorModuleFileNotFound(ref path, ref paths, ..) if path == "does_not_exist" && *paths == [file_path.clone()]));
+    assert!(err.to_string().contains(&file_path));
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_module_resolver_hot_reload() {
+    use rhai::module_resolvers::FileModuleResolver;
+    use rhai::{ModuleResolver, Position, Shared};
+
+    let dir = TempDir::new("hot-reload");
+    let file_path = dir.join("hot.rhai");
+    std::fs::write(&file_path, "export const x = 1;").unwrap();
+
+    let mut resolver = FileModuleResolver::new_with_path(dir.to_path_buf());
+    let engine = Engine::new();
+
+    // Cache the script file before hot-reloading is enabled
+    let m1 = resolver.resolve(&engine, None, "hot", Position::NONE).unwrap();
+
+    resolver.set_hot_reload(true);
+    assert!(resolver.is_hot_reload());
+
+    let m2 = resolver.resolve(&engine, None, "hot", Position::NONE).unwrap();
+    assert!(Shared::ptr_eq(&m1, &m2));
+
+    // Rewrite the file until its modification time advances (resolution may be coarse)
+    let modified = std::fs::metadata(&file_path).unwrap().modified().unwrap();
+    for _ in 0..300 {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        std::fs::write(&file_path, "export const x = 2;").unwrap();
+        if std::fs::metadata(&file_path).unwrap().modified().unwrap() > modified {
+            break;
+        }
+    }
+
+    let m3 = resolver.resolve(&engine, None, "hot", Position::NONE).unwrap();
+    let m4 = resolver.resolve(&engine, None, "hot", Position::NONE).unwrap();
+
+    assert!(!Shared::ptr_eq(&m1, &m3));
+    assert!(Shared::ptr_eq(&m3, &m4));
+    assert_eq!(m1.get_var_value::<INT>("x").unwrap(), 1);
+    assert_eq!(m3.get_var_value::<INT>("x").unwrap(), 2);
+}
+
+#[cfg(not(feature = "no_object"))]
+#[test]
+fn test_module_getter_setter() {
+    #[derive(Clone)]
+    struct Point {
+        x: INT,
+    }
+
+    let mut module = Module::new();
+    module.set_native_fn("point", || Ok(Point { x: 1 }));
+    module.set_getter_fn("x", |p: &mut Point| Ok(p.x));
+    module.set_setter_fn("x", |p: &mut Point, x: INT| {
+        if x < 0 {
+            return Err("x must not be negative".into());
+        }
+        p.x = x;
+        Ok(())
+    });
+    module.set_getter_fn("len", |_: &mut Point| Err::<INT, _>("no length".into()));
+
+    // Getters and setters are exposed to the global namespace even in a static module
+    let mut engine = Engine::new();
+    engine.register_static_module("geo", module.into());
+
+    assert_eq!(engine.eval::<INT>("let p = geo::point(); p.x").unwrap(), 1);
+    assert_eq!(engine.eval::<INT>("let p = geo::point(); p.x = 42; p.x").unwrap(), 42);
+    assert_eq!(engine.eval::<INT>("let p = geo::point(); p.x += 41; p.x").unwrap(), 42);
+    assert!(engine.run("let p = geo::point(); p.x = -1;").unwrap_err().to_string().contains("x must not be negative"));
+    assert!(engine.run("let p = geo::point(); p.len").unwrap_err().to_string().contains("no length"));
+}
+
+#[cfg(not(feature = "no_index"))]
+#[test]
+fn test_module_indexer() {
+    #[derive(Clone)]
+    struct Grid(Vec<INT>);
+
+    let mut module = Module::new();
+    module.set_native_fn("grid", || Ok(Grid(vec![0; 4])));
+    module.set_indexer_get_set_fn(
+        |g: &mut Grid, i: INT| g.0.get(i as usize).copied().ok_or_else(|| format!("bad index {i}").into()),
+        |g: &mut Grid, i: INT, v: INT| match g.0.get_mut(i as usize) {
+            Some(x) => {
+                *x = v;
+                Ok(())
+            }
+            None => Err(format!("bad index {i}").into()),
+        },
+    );
+    module.set_indexer_get_fn(|g: &mut Grid, name: &str| Ok(if name == "len" { g.0.len() as INT } else { -1 }));
+
+    let mut engine = Engine::new();
+    engine.register_static_module("grid", module.into());
+
+    assert_eq!(engine.eval::<INT>("let g = grid::grid(); g[1] = 42; g[1]").unwrap(), 42);
+    assert_eq!(engine.eval::<INT>("let g = grid::grid(); g[2] += 2; g[2] + g[0]").unwrap(), 2);
+    assert_eq!(engine.eval::<INT>(r#"let g = grid::grid(); g["len"]"#).unwrap(), 4);
+    assert!(engine.run("let g = grid::grid(); g[9]").unwrap_err().to_string().contains("bad index 9"));
+    assert!(engine.run("let g = grid::grid(); g[9] = 1;").unwrap_err().to_string().contains("bad index 9"));
+}
+
+#[test]
+fn test_module_iter_fn_info_sorted() {
+    let names = ["zeta", "alpha", "mu", "beta", "omega", "gamma", "delta", "pi"];
+
+    let mut module = Module::new();
+    for name in names {
+        module.set_native_fn(name, |x: INT| Ok(x));
+        module.set_native_fn(name, || Ok(0 as INT));
+    }
+    module.set_var("b", 2 as INT);
+    module.set_var("a", 1 as INT);
+
+    let mut expected: Vec<_> = names.iter().flat_map(|&name| [(name, 0), (name, 1)]).collect();
+    expected.sort();
+
+    let actual: Vec<_> = module.iter_fn_info_sorted().map(|(_, name, n)| (name, n)).collect();
+
+    assert_eq!(actual, expected);
+    assert_eq!(module.var_names().collect::<Vec<_>>(), ["a", "b"]);
+}
+
+#[test]
+fn test_module_register_functions() {
+    let mut module = Module::new();
+    module.set_native_fn("inc", |x: INT| Ok(x + 1));
+    module.set_native_fn("inc", |x: INT, y: INT| Ok(x + y));
+    module.set_native_fn("dec", |x: INT| Ok(x - 1));
+    module.set_fn_var_args("count", |_, args| Ok(args.len() as INT));
+    module.set_var("X", 42 as INT);
+
+    let mut engine = Engine::new();
+
+    let missing = engine.register_module_functions(&module, &["inc", "count", "X", "missing"]);
+    assert_eq!(missing, ["X", "missing"]);
+
+    assert_eq!(engine.eval::<INT>("inc(41)").unwrap(), 42);
+    assert_eq!(engine.eval::<INT>("inc(40, 2)").unwrap(), 42);
+    assert_eq!(engine.eval::<INT>("count(1, 2, 3)").unwrap(), 3);
+    assert!(matches!(*engine.run("dec(43)").unwrap_err(), EvalAltResult::ErrorFunctionNotFound(..)));
+    assert!(engine.run("X").is_err());
+}
+
+#[test]
+fn test_module_register_module_fns() {
+    let mut module = Module::new();
+
+    rhai::register_module_fns!(module, {
+        "zero" => || Ok(0 as INT),
+        "neg" => |x: INT| Ok(-x),
+        "add" => |x: INT, y: INT| Ok(x + y),
+        "add" => |x: INT, y: INT, z: INT| Ok(x + y + z),
+        "len" => |s: &str| Ok(s.len() as INT),
+    });
+
+    assert_eq!(module.fn_count(), 5);
+    assert!(module.contains_fn_by_name("add", 2));
+    assert!(module.contains_fn_by_name("add", 3));
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.into());
+
+    assert_eq!(engine.eval::<INT>(r#"m::add(m::zero(), m::neg(-40), m::len("ab"))"#).unwrap(), 42);
+    assert_eq!(engine.eval::<INT>("m::add(40, 2)").unwrap(), 42);
+}
+
+#[test]
+fn test_module_var_type_name() {
+    #[derive(Clone)]
+    struct Point;
+
+    let mut module = Module::new();
+    module.set_var("int", 42 as INT);
+    #[cfg(not(feature = "no_float"))]
+    module.set_var("float", 1.5 as rhai::FLOAT);
+    module.set_var("string", "hello");
+    module.set_var("point", Point);
+
+    assert_eq!(module.var_type_name("int"), Some(std::any::type_name::<INT>()));
+    #[cfg(not(feature = "no_float"))]
+    assert_eq!(module.var_type_name("float"), Some(std::any::type_name::<rhai::FLOAT>()));
+    assert_eq!(module.var_type_name("string"), Some("string"));
+    assert_eq!(module.var_type_name("point"), Some(std::any::type_name::<Point>()));
+    assert_eq!(module.var_type_name("missing"), None);
+
+    assert!(module.get_var("point").unwrap().is::<Point>());
+    assert!(!module.get_var("point").unwrap().is::<INT>());
+
+    let mut engine = Engine::new();
+    engine.register_type_with_name::<Point>("Point");
+    assert_eq!(engine.map_type_name(module.var_type_name("point").unwrap()), "Point");
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_module_prelude() {
+    use rhai::module_resolvers::FileModuleResolver;
+    use rhai::FnNamespace;
+
+    let dir = TempDir::new("prelude");
+    std::fs::write(dir.join("shapes.rhai"), "export const area = SIDE * SIDE; export const tripled = triple(SIDE); export SIDE as side;").unwrap();
+
+    let mut prelude = Module::new();
+    prelude.set_var("SIDE", 7 as INT);
+    let hash = prelude.set_native_fn("triple", |x: INT| Ok(x * 3));
+    prelude.update_fn_namespace(hash, FnNamespace::Global);
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(FileModuleResolver::new_with_path(dir.to_path_buf()));
+    engine.set_module_prelude(prelude);
+
+    assert_eq!(engine.eval::<INT>(r#"import "shapes" as s; s::area + s::tripled"#).unwrap(), 70);
+
+    // Prelude entries are not exported unless re-exported
+    assert_eq!(engine.eval::<INT>(r#"import "shapes" as s; s::side"#).unwrap(), 7);
+    assert!(engine.eval::<INT>(r#"import "shapes" as s; s::SIDE"#).is_err());
+    assert!(engine.eval::<INT>(r#"import "shapes" as s; s::triple(1)"#).is_err());
+
+    // The prelude is only available to module scripts
+    assert!(engine.eval::<INT>("SIDE").is_err());
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_module_resolver_relative_to_importer() {
+    use rhai::module_resolvers::FileModuleResolver;
+
+    let dir = TempDir::new("relative");
+    std::fs::create_dir_all(dir.join("lib")).unwrap();
+    std::fs::write(dir.join("helpers.rhai"), "export const x = 1;").unwrap();
+    std::fs::write(dir.join("lib").join("helpers.rhai"), "export const x = 2;").unwrap();
+    std::fs::write(dir.join("lib").join("util.rhai"), r#"import "./helpers" as h; export const x = h::x;"#).unwrap();
+    std::fs::write(dir.join("lib").join("bare.rhai"), r#"import "helpers" as h; export const x = h::x;"#).unwrap();
+    std::fs::write(dir.join("top.rhai"), "export const x = 3;").unwrap();
+    std::fs::write(dir.join("lib").join("fallback.rhai"), r#"import "top" as t; export const x = t::x;"#).unwrap();
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(FileModuleResolver::new_with_path(dir.to_path_buf()).with_relative_to_importer(true));
+
+    let result_relative = engine.eval::<INT>(r#"import "lib/util" as m; m::x"#);
+    let result_bare = engine.eval::<INT>(r#"import "lib/bare" as m; m::x"#);
+    let result_fallback = engine.eval::<INT>(r#"import "lib/fallback" as m; m::x"#);
+
+    engine.set_module_resolver(FileModuleResolver::new_with_path(dir.to_path_buf()));
+
+    let result_disabled = engine.eval::<INT>(r#"import "lib/util" as m; m::x"#);
+    let result_bare_disabled = engine.eval::<INT>(r#"import "lib/bare" as m; m::x"#);
+
+    assert_eq!(result_relative.unwrap(), 2);
+    assert_eq!(result_bare.unwrap(), 2);
+    assert_eq!(result_fallback.unwrap(), 3);
+    assert_eq!(result_disabled.unwrap(), 1);
+    assert_eq!(result_bare_disabled.unwrap(), 1);
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_module_resolver_caching_relative_to_importer() {
+    use rhai::module_resolvers::{CachingModuleResolver, FileModuleResolver};
+
+    let dir = TempDir::new("caching_relative");
+    for (sub_dir, value) in [("a", 1), ("b", 2)] {
+        std::fs::create_dir_all(dir.join(sub_dir)).unwrap();
+        std::fs::write(dir.join(sub_dir).join("helpers.rhai"), format!("export const x = {value};")).unwrap();
+        std::fs::write(dir.join(sub_dir).join("m.rhai"), r#"import "helpers" as h; export const x = h::x;"#).unwrap();
+    }
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(CachingModuleResolver::new(FileModuleResolver::new_with_path(dir.to_path_buf()).with_relative_to_importer(true)));
+
+    // The same path imported from different directories is cached separately
+    assert_eq!(engine.eval::<INT>(r#"import "a/m" as a; import "b/m" as b; a::x * 10 + b::x"#).unwrap(), 12);
+}
+
+#[cfg(not(feature = "no_function"))]
+#[test]
+fn test_module_merge_ast_functions() {
+    let engine = Engine::new();
+    let ast = engine.compile("fn double(x) { x * 2 } fn triple(x) { x * 3 }").unwrap();
+
+    let mut module = Module::new();
+    module.set_native_fn("negate", |x: INT| Ok(-x));
+    module.merge(ast.as_ref());
+
+    assert_eq!(module.count().1, 3);
+    assert!(module.contains_fn_by_name("double", 1));
+    assert!(module.contains_fn_by_name("triple", 1));
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.into());
+
+    assert_eq!(engine.eval::<INT>("m::double(m::negate(21))").unwrap(), -42);
+    assert_eq!(engine.eval::<INT>("m::triple(2)").unwrap(), 6);
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_module_resolver_case_insensitive_extension() {
+    use rhai::module_resolvers::FileModuleResolver;
+
+    let dir = TempDir::new("extension-case");
+    std::fs::write(dir.join("Util.RHAI"), "export const x = 1;").unwrap();
+    std::fs::write(dir.join("title.Rhai"), "export const x = 2;").unwrap();
+
+    let resolver = FileModuleResolver::new_with_path(dir.to_path_buf()).with_case_insensitive_extension(true);
+    assert_eq!(resolver.search_paths("missing", None), [dir.join("missing.rhai"), dir.join("missing.RHAI"), dir.join("missing.Rhai")]);
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+
+    let result_upper = engine.eval::<INT>(r#"import "Util" as m; m::x"#);
+    let result_title = engine.eval::<INT>(r#"import "title" as m; m::x"#);
+    let result_missing = engine.run(r#"import "missing" as m;"#);
+
+    engine.set_module_resolver(FileModuleResolver::new_with_path(dir.to_path_buf()));
+
+    let result_disabled = engine.run(r#"import "Util" as m;"#);
+
+    assert_eq!(result_upper.unwrap(), 1);
+    assert_eq!(result_title.unwrap(), 2);
+
+    assert!(matches!(*result_missing.unwrap_err(), EvalAltResult::ErrorModuleFileNotFound(ref path, ref paths, ..) if path == "missing" && paths.len() == 3));
+
+    // Only case-sensitive file systems distinguish extensions by case
+    #[cfg(target_os = "linux")]
+    assert!(matches!(*result_disabled.unwrap_err(), EvalAltResult::ErrorModuleFileNotFound(..)));
+    #[cfg(not(target_os = "linux"))]
+    let _ = result_disabled;
+}
+
+#[cfg(not(feature = "no_index"))]
+#[test]
+fn test_module_get_var_ref() {
+    let mut module = Module::new();
+    module.set_var("data", (0..1000).map(Dynamic::from).collect::<rhai::Array>());
+    module.set_var("answer", 42 as INT);
+
+    let data = module.get_var_ref::<rhai::Array>("data").unwrap();
+    assert_eq!(data.len(), 1000);
+
+    // No clone: every reference points to the same stored array
+    assert_eq!(module.get_var_ref::<rhai::Array>("data").unwrap().as_ptr(), data.as_ptr());
+    assert_ne!(module.get_var_value::<rhai::Array>("data").unwrap().as_ptr(), data.as_ptr());
+
+    assert_eq!(module.get_var_ref::<INT>("answer"), Some(&42));
+    assert!(module.get_var_ref::<rhai::Array>("answer").is_none());
+    assert!(module.get_var_ref::<INT>("missing").is_none());
+}
+
+#[test]
+fn test_module_on_import() {
+    let mut resolver = StaticModuleResolver::new();
+    let mut module = Module::new();
+    module.set_var("answer", 42 as INT);
+    resolver.insert("hello", module);
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+
+    let log = std::sync::Arc::new(std::sync::RwLock::new(Vec::new()));
+    let logger = log.clone();
+
+    engine.on_import(move |path, name| {
+        logger.write().unwrap().push(format!("{path}:{name}"));
+        if path == "forbidden" {
+            Err("import vetoed".into())
+        } else {
+            Ok(())
+        }
+    });
+
+    assert_eq!(engine.eval::<INT>(r#"import "hello" as h; import "hello"; h::answer"#).unwrap(), 42);
+    assert!(matches!(*engine.run(r#"import "missing" as m;"#).unwrap_err(), EvalAltResult::ErrorModuleNotFound(..)));
+
+    let err = engine.run(r#"let x = 1; import "forbidden" as f;"#).unwrap_err();
+    assert!(matches!(&*err, EvalAltResult::ErrorRuntime(msg, ..) if msg.to_string() == "import vetoed"));
+    #[cfg(not(feature = "no_position"))]
+    assert_eq!(err.position(), rhai::Position::new(1, 19));
+
+    assert_eq!(*log.read().unwrap(), ["hello:h", "hello:", "missing:m", "forbidden:f"]);
+}
+
+#[cfg(not(feature = "no_function"))]
+#[test]
+fn test_module_native_fn_context() {
+    let mut module = Module::new();
+    module.set_native_fn("twice", |context: rhai::NativeCallContext, x: INT| {
+        let y = context.call_fn::<INT>("double", (x,))?;
+        context.call_fn::<INT>("double", (y,))
+    });
+    module.set_native_fn("apply", |context: rhai::NativeCallContext, fp: rhai::FnPtr, x: INT| fp.call_within_context::<INT>(&context, (x,)));
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.into());
+
+    assert_eq!(engine.eval::<INT>("fn double(x) { x * 2 } m::twice(10)").unwrap(), 40);
+    assert_eq!(engine.eval::<INT>("m::apply(|x| x + 1, 41)").unwrap(), 42);
+}
+
+#[test]
+fn test_module_resolver_static_lazy() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let built = std::sync::Arc::new(AtomicUsize::new(0));
+    let counter = built.clone();
+
+    let mut resolver = StaticModuleResolver::new();
+    resolver.insert_lazy("lazy", move || {
+        counter.fetch_add(1, Ordering::SeqCst);
+        let mut module = Module::new();
+        module.set_var("answer", 42 as INT);
+        module
+    });
+    resolver.insert("eager", Module::new());
+    resolver.add_alias("alias", "lazy");
+
+    // Lazily-constructed modules are only counted by their paths
+    assert_eq!(resolver.len(), 1);
+    assert_eq!(resolver.len(), resolver.iter().count());
+    assert_eq!(resolver.paths().count(), 2);
+    assert!(!resolver.is_empty());
+    assert!(resolver.contains_path("lazy"));
+    assert!(resolver.contains_path("alias"));
+    assert_eq!(built.load(Ordering::SeqCst), 0);
+
+    let mut lazy_only = StaticModuleResolver::new();
+    lazy_only.insert_lazy("lazy", Module::new);
+    assert_eq!(lazy_only.len(), 0);
+    assert!(!lazy_only.is_empty());
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+
+    engine.run(r#"import "eager" as e;"#).unwrap();
+    assert_eq!(built.load(Ordering::SeqCst), 0);
+
+    assert_eq!(engine.eval::<INT>(r#"import "lazy" as m; m::answer"#).unwrap(), 42);
+    assert_eq!(engine.eval::<INT>(r#"import "alias" as m; import "lazy" as n; m::answer + n::answer"#).unwrap(), 84);
+    assert_eq!(built.load(Ordering::SeqCst), 1);
+}
+
+#[cfg(feature = "internals")]
+#[test]
+fn test_module_set_fn_with_hash() {
+    use std::any::TypeId;
+
+    fn double(_: Option<rhai::NativeCallContext>, args: &mut [&mut Dynamic]) -> Result<Dynamic, Box<EvalAltResult>> {
+        Ok(Dynamic::from(args[0].as_int().unwrap() * 2))
+    }
+
+    let mut module = Module::new();
+    let hash = module.set_native_fn("calc", |x: INT| Ok(x + 1));
+
+    let func = rhai::RhaiFunc::Pure {
+        func: rhai::Shared::new(double),
+        has_context: false,
+        is_pure: true,
+        is_volatile: false,
+    };
+
+    let mut module = Module::new();
+    assert_eq!(module.set_fn_with_hash(hash, "calc", [TypeId::of::<INT>()], func.clone()), hash);
+    assert!(module.contains_fn(hash));
+    assert!(module.contains_fn_by_name("calc", 1));
+
+    let unrelated = module.set_fn_with_hash(42, "unrelated", [TypeId::of::<INT>()], func);
+    assert_eq!(unrelated, 42);
+    assert!(module.contains_fn(42));
+
+    let mut engine = Engine::new();
+    engine.register_global_module(module.into());
+
+    assert_eq!(engine.eval::<INT>("calc(21)").unwrap(), 42);
+    assert!(engine.eval::<INT>("unrelated(21)").is_err());
+}
+
+#[cfg(not(feature = "no_std"))]
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_module_resolver_path_with_dots() {
+    use rhai::module_resolvers::FileModuleResolver;
+
+    let dir = TempDir::new("path-dots");
+    std::fs::create_dir_all(dir.join("sub.dir")).unwrap();
+    std::fs::write(dir.join("sub.dir").join("mod.rhai"), "export const x = 1;").unwrap();
+    std::fs::write(dir.join("my module.rhai"), "export const x = 2;").unwrap();
+    std::fs::write(dir.join("my.module.rhai"), "export const x = 3;").unwrap();
+
+    let resolver = FileModuleResolver::new_with_path(dir.to_path_buf());
+    assert_eq!(resolver.get_file_path("my.module", None), dir.join("my.module.rhai"));
+    assert_eq!(resolver.get_file_path("my.module.rhai", None), dir.join("my.module.rhai"));
+    assert_eq!(resolver.get_file_path("my.module.RHAI", None), dir.join("my.module.rhai"));
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+
+    let result_dir = engine.eval::<INT>(r#"import "sub.dir/mod" as m; m::x"#);
+    let result_space = engine.eval::<INT>(r#"import "my module" as m; m::x"#);
+    let result_dots = engine.eval::<INT>(r#"import "my.module" as m; m::x"#);
+    let result_ext = engine.eval::<INT>(r#"import "my.module.rhai" as m; m::x"#);
+
+    assert_eq!(result_dir.unwrap(), 1);
+    assert_eq!(result_space.unwrap(), 2);
+    assert_eq!(result_dots.unwrap(), 3);
+    assert_eq!(result_ext.unwrap(), 3);
+}
+
+#[test]
+fn test_module_fn_not_found_arg_types() {
+    let mut module = Module::new();
+    module.set_native_fn("foo", |s: ImmutableString| Ok(s.len() as INT));
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.into());
+
+    let int_type = Dynamic::from(0 as INT).type_name();
+
+    let EvalAltResult::ErrorFunctionNotFound(sig, ..) = *engine.eval::<INT>("m::foo(42)").unwrap_err() else { panic!() };
+    assert_eq!(sig, format!("m::foo ({int_type})"));
+
+    let EvalAltResult::ErrorFunctionNotFound(sig, ..) = *engine.eval::<INT>(r#"foo(42, "x")"#).unwrap_err() else { panic!() };
+    assert_eq!(sig, format!("foo ({int_type}, &str | ImmutableString | String)"));
+}
+
+#[cfg(feature = "sync")]
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn test_module_send_sync() {
+    let mut module = Module::new();
+    module.set_var("answer", 42 as INT);
+    module.set_native_fn("calc", |x: INT| Ok(x + 1));
+
+    let module: rhai::Shared<Module> = module.into();
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let module = module.clone();
+            std::thread::spawn(move || {
+                let mut engine = Engine::new();
+                engine.register_static_module("m", module);
+                engine.eval::<INT>("m::calc(m::answer)").unwrap()
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), 43);
+    }
+}
+
+#[test]
+fn test_module_implicit_import_alias() {
+    let mut module = Module::new();
+    module.set_var("answer", 42 as INT);
+
+    let mut resolver = StaticModuleResolver::new();
+    resolver.insert("lib/util.rhai", module.clone());
+    resolver.insert("my module", module);
+
+    let mut engine = Engine::new();
+    engine.set_module_resolver(resolver);
+
+    assert!(!engine.implicit_import_alias());
+    assert!(engine.eval::<INT>(r#"import "lib/util.rhai"; util::answer"#).is_err());
+
+    engine.set_implicit_import_alias(true);
+
+    assert_eq!(engine.eval::<INT>(r#"import "lib/util.rhai"; util::answer"#).unwrap(), 42);
+    assert_eq!(engine.eval::<INT>(r#"import "lib/util.rhai" as u; u::answer"#).unwrap(), 42);
+    assert!(engine.eval::<INT>(r#"import "lib/util.rhai" as u; util::answer"#).is_err());
+    assert!(engine.run(r#"import "my module";"#).is_ok());
+
+    // The parser knows the implicit alias
+    engine.set_strict_variables(true);
+    assert!(engine.compile(r#"import "lib/util.rhai"; util::answer"#).is_ok());
+    assert!(engine.compile(r#"let path = "lib/util.rhai"; import path; util::answer"#).is_err());
+}
+
+#[cfg(not(feature = "no_index"))]
+#[cfg(not(feature = "no_object"))]
+#[test]
+fn test_module_set_var_containers() {
+    let mut config = std::collections::HashMap::new();
+    config.insert("retries".to_string(), 3 as INT);
+    config.insert("timeout".to_string(), 30 as INT);
+
+    let mut module = Module::new();
+    module.set_var_array("primes", vec![2 as INT, 3, 5, 7]);
+    module.set_var_array("names", ["a", "b"]);
+    module.set_var_map("config", config);
+
+    let mut engine = Engine::new();
+    engine.register_static_module("m", module.into());
+
+    assert_eq!(engine.eval::<INT>("m::primes.len()").unwrap(), 4);
+    assert_eq!(engine.eval::<INT>("m::primes[3]").unwrap(), 7);
+    assert_eq!(engine.eval::<String>("m::names[1]").unwrap(), "b");
+    assert_eq!(engine.eval::<String>("type_of(m::primes)").unwrap(), "array");
+    assert_eq!(engine.eval::<INT>("m::config.retries + m::config.timeout").unwrap(), 33);
+    assert_eq!(engine.eval::<String>("type_of(m::config)").unwrap(), "map");
+}
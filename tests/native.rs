@@ -53,3 +53,37 @@ fn test_native_overload() {
     assert_eq!(engine.eval::<String>(r#"let x = "hello"; let y = "world"; x + y"#).unwrap(), "hello***world");
     assert_eq!(engine.eval::<String>(r#"let x = "hello"; let y = (); x + y"#).unwrap(), "hello Foo!");
 }
+
+#[cfg(not(feature = "no_position"))]
+#[test]
+fn test_native_error_with_position() {
+    let mut engine = Engine::new();
+
+    engine.register_fn("check", |ctx: NativeCallContext, x: INT| -> Result<INT, Box<EvalAltResult>> {
+        if x < 0 {
+            let err: Box<EvalAltResult> = "negative".into();
+            Err(err.with_position(ctx.position()))
+        } else {
+            Ok(x)
+        }
+    });
+    engine.register_fn("fail_at", |line: INT, pos: INT| -> Result<(), Box<EvalAltResult>> {
+        let err: Box<EvalAltResult> = "failed".into();
+        Err(err.with_position(rhai::Position::new(line as u16, pos as u16)))
+    });
+
+    assert_eq!(engine.eval::<INT>("check(42)").unwrap(), 42);
+
+    let err = engine.eval::<INT>("let x = -1;\ncheck(x)").unwrap_err();
+    assert_eq!(err.position().line(), Some(2));
+    assert_eq!(err.position().position(), Some(1));
+
+    // An explicit position is kept, overriding the position of the call
+    let err = engine.run("fail_at(7, 3)").unwrap_err();
+    assert_eq!(err.position().line(), Some(7));
+    assert_eq!(err.position().position(), Some(3));
+
+    let err: Box<EvalAltResult> = "moved".into();
+    let err = err.with_position(rhai::Position::new(1, 5)).with_position(rhai::Position::new(2, 1));
+    assert_eq!(err.position().line(), Some(2));
+}
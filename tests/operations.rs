@@ -24,6 +24,18 @@ fn test_max_operations() {
     engine.run("for x in 0..10000 {}").unwrap();
 }
 
+#[test]
+fn test_max_operations_infinite_loop() {
+    let mut engine = Engine::new();
+    engine.set_max_operations(1000);
+
+    assert!(matches!(*engine.run("loop {}").unwrap_err(), EvalAltResult::ErrorTooManyOperations(..)));
+    assert!(matches!(*engine.run("while true {}").unwrap_err(), EvalAltResult::ErrorTooManyOperations(..)));
+    assert!(matches!(*engine.run("let x = 0; loop { x += 1; }").unwrap_err(), EvalAltResult::ErrorTooManyOperations(..)));
+    #[cfg(not(feature = "no_function"))]
+    assert!(matches!(*engine.run("fn f() { loop {} } f()").unwrap_err(), EvalAltResult::ErrorTooManyOperations(..)));
+}
+
 #[test]
 fn test_max_operations_literal() {
     let mut engine = Engine::new();
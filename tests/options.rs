@@ -1,4 +1,4 @@
-use rhai::{Engine, Scope, INT};
+use rhai::{CompileOptions, Engine, Scope, INT};
 
 #[test]
 fn test_options_allow() {
@@ -113,3 +113,40 @@ fn test_options_strict_var() {
         assert_eq!(engine.eval_with_scope::<INT>(&mut scope, "fn foo(z) { y + z } foo(x)").unwrap(), 42);
     }
 }
+
+#[test]
+fn test_options_compile_options() {
+    let engine = Engine::new();
+    let options = CompileOptions::new().with_strict_variables(true);
+
+    engine.compile("x + 1").unwrap();
+    assert!(engine.compile_with_options(&Scope::new(), "x + 1", options).is_err());
+    #[cfg(not(feature = "no_function"))]
+    assert!(engine.compile_with_options(&Scope::new(), "fn foo() { x + 1 }", options).is_err());
+
+    let mut scope = Scope::new();
+    scope.push("x", 41 as INT);
+    let ast = engine.compile_with_options(&scope, "x + 1", options).unwrap();
+    assert_eq!(engine.eval_ast_with_scope::<INT>(&mut scope, &ast).unwrap(), 42);
+
+    #[cfg(not(feature = "no_optimize"))]
+    {
+        let options = CompileOptions::new().with_optimization_level(rhai::OptimizationLevel::None);
+        let ast = engine.compile_with_options(&Scope::new(), "40 + 2", options).unwrap();
+        assert_eq!(engine.eval_ast::<INT>(&ast).unwrap(), 42);
+    }
+}
+
+#[test]
+fn test_options_supports() {
+    assert_eq!(Engine::supports_float(), cfg!(not(feature = "no_float")));
+    assert_eq!(Engine::supports_index(), cfg!(not(feature = "no_index")));
+    assert_eq!(Engine::supports_object(), cfg!(not(feature = "no_object")));
+    assert_eq!(Engine::supports_function(), cfg!(not(feature = "no_function")));
+
+    let mut module = rhai::Module::new();
+    let hash = module.set_fn_if(Engine::supports_float(), "half", |x: INT| Ok(x as f64 / 2.0));
+    assert_eq!(hash.is_some(), Engine::supports_float());
+    assert_eq!(hash.map_or(false, |h| module.contains_fn(h)), Engine::supports_float());
+    assert!(module.set_fn_if(false, "calc", |x: INT| Ok(x + 1)).is_none());
+}
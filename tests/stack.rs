@@ -34,6 +34,52 @@ fn test_stack_overflow_fn_calls() {
     ));
 }
 
+#[test]
+#[cfg(not(feature = "no_function"))]
+fn test_stack_overflow_unbounded_recursion() {
+    let mut engine = Engine::new();
+    engine.set_max_call_levels(20);
+
+    // Mutual recursion
+    assert!(matches!(*engine.run("fn ping(n) { pong(n + 1) } fn pong(n) { ping(n + 1) } ping(0)").unwrap_err(), EvalAltResult::ErrorStackOverflow(..)));
+
+    // Module-qualified recursion
+    #[cfg(not(feature = "no_module"))]
+    {
+        let ast = engine.compile("fn rec(n) { rec(n + 1) }").unwrap();
+        let module = rhai::Module::eval_ast_as_new(rhai::Scope::new(), &ast, &engine).unwrap();
+        engine.register_static_module("m", module.into());
+
+        assert!(matches!(*engine.run("m::rec(0)").unwrap_err(), EvalAltResult::ErrorStackOverflow(..)));
+        assert!(matches!(*engine.run("fn f(n) { m::rec(n) } f(0)").unwrap_err(), EvalAltResult::ErrorStackOverflow(..)));
+    }
+}
+
+#[test]
+#[cfg(not(feature = "no_function"))]
+fn test_max_script_fn_calls() {
+    let mut engine = Engine::new();
+    engine.set_max_script_fn_calls(5);
+
+    let script = "fn foo(n) { if n <= 0 { 0 } else { 1 + foo(n-1) } }";
+
+    assert_eq!(engine.eval::<INT>(&format!("{script} foo(4)")).unwrap(), 4);
+    assert!(matches!(*engine.run(&format!("{script} foo(5)")).unwrap_err(), EvalAltResult::ErrorTooManyFunctionCalls(..)));
+
+    // The count is cumulative, not the depth
+    assert!(matches!(*engine.run(&format!("{script} for i in 0..3 {{ foo(1); }}")).unwrap_err(), EvalAltResult::ErrorTooManyFunctionCalls(..)));
+
+    #[cfg(not(feature = "no_module"))]
+    {
+        let ast = engine.compile(script).unwrap();
+        let module = rhai::Module::eval_ast_as_new(rhai::Scope::new(), &ast, &engine).unwrap();
+        engine.register_static_module("m", module.into());
+
+        assert_eq!(engine.eval::<INT>("m::foo(3)").unwrap(), 3);
+        assert!(matches!(*engine.run("m::foo(7)").unwrap_err(), EvalAltResult::ErrorTooManyFunctionCalls(..)));
+    }
+}
+
 #[test]
 fn test_stack_overflow_parsing() {
     let mut engine = Engine::new();
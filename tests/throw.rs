@@ -95,3 +95,53 @@ fn test_try_catch() {
     #[cfg(not(feature = "unchecked"))]
     assert!(matches!(*engine.run("try { 42/0; } catch { throw; }").expect_err("expects error"), EvalAltResult::ErrorArithmetic(..)));
 }
+
+#[test]
+fn test_try_catch_native_payload() {
+    let mut engine = Engine::new();
+
+    engine.register_fn("check", |x: INT| -> Result<INT, Box<EvalAltResult>> {
+        if x < 0 {
+            rhai::fail_with(x * 2, rhai::Position::NONE)
+        } else {
+            Ok(x)
+        }
+    });
+
+    assert_eq!(engine.eval::<INT>("check(42)").unwrap(), 42);
+    assert_eq!(engine.eval::<INT>("try { check(-21) } catch (err) { -err }").unwrap(), 42);
+
+    #[cfg(not(feature = "no_function"))]
+    assert_eq!(engine.eval::<INT>("fn foo(x) { check(x) } try { foo(-21) } catch (err) { -err }").unwrap(), 42);
+
+    assert!(matches!(*engine.run("check(-1)").unwrap_err(), EvalAltResult::ErrorRuntime(v, ..) if v.as_int().unwrap() == -2));
+}
+
+#[test]
+fn test_try_catch_native_typed_payload() {
+    #[derive(Debug, Clone, PartialEq)]
+    struct IoError {
+        code: INT,
+    }
+
+    let mut engine = Engine::new();
+
+    engine.register_fn("read", |code: INT| -> Result<INT, Box<EvalAltResult>> { rhai::fail_with(IoError { code }, rhai::Position::NONE) });
+    #[cfg(not(feature = "no_object"))]
+    engine.register_get("code", |e: &mut IoError| e.code);
+
+    // The host can downcast the payload after evaluation
+    let mut err = engine.run("read(2)").unwrap_err();
+    err.set_position(rhai::Position::new(3, 4));
+
+    match *err {
+        EvalAltResult::ErrorRuntime(ref v, ..) => assert_eq!(v.clone().try_cast::<IoError>().unwrap(), IoError { code: 2 }),
+        ref err => panic!("{err}"),
+    }
+    #[cfg(not(feature = "no_position"))]
+    assert_eq!(err.position(), rhai::Position::new(3, 4));
+
+    // A catching script receives the payload itself
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval::<INT>("try { read(13) } catch (err) { err.code }").unwrap(), 13);
+}
@@ -71,6 +71,54 @@ fn test_tokens_custom_operator_symbol() {
     assert_eq!(engine.eval_expression::<INT>("1 + 2 * 3 => 4 - 5 / 6").unwrap(), 15);
 }
 
+#[cfg(not(feature = "no_custom_syntax"))]
+#[test]
+fn test_tokens_custom_operator_module() {
+    let mut module = rhai::Module::new();
+    module.set_op_fn_with_precedence("#", 160, |x: INT, y: INT| Ok((x * y) - (x + y)));
+    module.set_op_fn_with_precedence("=>", 160, |x: INT, y: INT| Ok(x + y));
+
+    let mut engine = Engine::new();
+    assert!(engine.compile_expression("1 + 2 * 3 # 4 - 5 / 6").is_err());
+    engine.register_global_module(module.into());
+
+    assert_eq!(engine.eval_expression::<INT>("1 + 2 * 3 # 4 - 5 / 6").unwrap(), 15);
+
+    // Reserved symbols are not registered as operators
+    assert!(engine.compile_expression("1 => 2").is_err());
+
+    // The latest registration of an operator sets its precedence
+    let mut low = rhai::Module::new();
+    low.set_op_fn_with_precedence("#", 10, |x: INT, y: INT| Ok((x * y) - (x + y)));
+    assert_eq!(engine.eval_expression::<INT>("1 + 2 # 3").unwrap(), 2);
+    engine.register_global_module(low.into());
+    assert_eq!(engine.eval_expression::<INT>("1 + 2 # 3").unwrap(), 3);
+
+    #[cfg(not(feature = "no_module"))]
+    {
+        let mut sub_module = rhai::Module::new();
+        sub_module.set_op_fn_with_precedence("~", 200, |x: INT, y: INT| Ok(x * 10 + y));
+        let mut module = rhai::Module::new();
+        module.set_sub_module("inner", sub_module);
+
+        let mut engine = Engine::new();
+        engine.register_static_module("m", module.into());
+
+        assert_eq!(engine.eval_expression::<INT>("1 + 2 ~ 3 ~ 4").unwrap(), 235);
+
+        // Operators of imported modules have no effect
+        let mut module = rhai::Module::new();
+        module.set_op_fn_with_precedence("~", 200, |x: INT, y: INT| Ok(x * 10 + y));
+        let mut resolver = rhai::module_resolvers::StaticModuleResolver::new();
+        resolver.insert("ops", module);
+
+        let mut engine = Engine::new();
+        engine.set_module_resolver(resolver);
+
+        assert!(engine.compile(r#"import "ops" as ops; 1 ~ 2"#).is_err());
+    }
+}
+
 #[test]
 fn test_tokens_unicode_xid_ident() {
     let engine = Engine::new();
@@ -452,3 +452,29 @@ fn test_var_scope_cloning() {
     engine.run_with_scope(&mut scope, "let x = 42; print(x + foo.field);").unwrap();
     assert_eq!(engine.eval_with_scope::<INT>(&mut scope, "let x = 42; x + foo.field").unwrap(), 43);
 }
+
+#[test]
+fn test_var_scope_snapshot() {
+    let engine = Engine::new();
+
+    let mut scope = Scope::new();
+    scope.push("x", 40 as INT);
+    scope.push_constant("LIMIT", 100 as INT);
+
+    let snapshot = scope.snapshot();
+
+    for _ in 0..3 {
+        engine.run_with_scope(&mut scope, "x += 2; let y = x;").unwrap();
+        assert_eq!(scope.get_value::<INT>("x").unwrap(), 42);
+        assert_eq!(scope.get_value::<INT>("y").unwrap(), 42);
+        assert_eq!(scope.len(), 3);
+
+        scope.restore(&snapshot);
+        assert_eq!(scope.get_value::<INT>("x").unwrap(), 40);
+        assert!(!scope.contains("y"));
+        assert_eq!(scope.len(), 2);
+        assert_eq!(scope.is_constant("LIMIT"), Some(true));
+    }
+
+    assert!(matches!(*engine.run_with_scope(&mut scope, "LIMIT = 0;").unwrap_err(), EvalAltResult::ErrorAssignmentToConstant(..)));
+}